@@ -16,6 +16,8 @@ pub use identity::Identity;
 pub use relu::ReLU;
 pub use softmax::Softmax;
 
+pub(crate) use softmax::stable_softmax;
+
 /// Network activation function abstraction.
 pub trait Activation<const B: usize, T: Numeric, const N: usize> {
     /// Construct a new network activation function.
@@ -25,5 +27,8 @@ pub trait Activation<const B: usize, T: Numeric, const N: usize> {
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N>;
 
     /// Complete a backward pass through this activation function.
+    /// 
+    /// This maps the gradients of the output of the last forward pass to the
+    /// gradients of its input.
     fn backward(&self, batch: &Batch<B, T, N>) -> Batch<B, T, N>;
 }
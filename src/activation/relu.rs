@@ -9,14 +9,20 @@ use crate::{
     tensor::Batch,
 };
 
-pub struct ReLU<const N: usize>;
+pub struct ReLU<const B: usize, T: Numeric, const N: usize> {
+    input: Batch<B, T, N>,
+}
 
-impl<const B: usize, T: Numeric, const N: usize> Activation<B, T, N> for ReLU<N> {
+impl<const B: usize, T: Numeric, const N: usize> Activation<B, T, N> for ReLU<B, T, N> {
     fn new() -> Self {
-        Self
+        Self {
+            input: Batch::zero(),
+        }
     }
 
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
+        self.input = batch.clone();
+
         let mut result = Batch::<B, T, N>::zero();
 
         for b in 0..B {
@@ -37,8 +43,9 @@ impl<const B: usize, T: Numeric, const N: usize> Activation<B, T, N> for ReLU<N>
 
         for b in 0..B {
             for i in 0..N {
-                result[b][i] = if batch[b][i] != T::zero() {
-                    T::one()
+                // Pass gradients only where the input was passed forward
+                result[b][i] = if self.input[b][i] >= T::zero() {
+                    batch[b][i]
                 } else {
                     T::zero()
                 };
@@ -22,35 +22,14 @@ impl<const B: usize, T: Numeric, const N: usize> Activation<B, T, N> for Softmax
 
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
         let mut result = Batch::<B, T, N>::zero();
-        let mut denom = [T::zero(); B];
-        let mut maxval = [T::zero(); B];
 
         // TODO what about integers?
 
-        // Store max value in each batch
         for b in 0..B {
-            for i in 0..N {
-                if batch[b][i] > maxval[b] {
-                    maxval[b] = batch[b][i];
-                }
-            }
-        }
-
-        // Compute denominator
-        for b in 0..B {
-            for i in 0..N {
-                denom[b] = denom[b] + T::exp(batch[b][i] - maxval[b]);
-                
-            }
-        }
-
-        for b in 0..B {
-            for i in 0..N {
-                result[b][i] = T::exp(batch[b][i] - maxval[b]) / denom[b];
-            }
+            stable_softmax(batch[b].as_array(), result[b].as_mut_array());
         }
 
-        self.output = result;
+        self.output = result.clone();
 
         result
     }
@@ -71,8 +50,30 @@ impl<const B: usize, T: Numeric, const N: usize> Activation<B, T, N> for Softmax
             }
         }
 
-        dbg!(batch, result, self.output);
-
         result
     }
 }
+
+/// Compute the softmax of `input` into `output`.
+/// 
+/// The largest input value is subtracted before exponentiating, so that large
+/// values do not overflow.
+pub(crate) fn stable_softmax<T: Numeric>(input: &[T], output: &mut [T]) {
+    // Find largest value
+    let mut maxval = input[0];
+    for &x in input {
+        if x > maxval {
+            maxval = x;
+        }
+    }
+
+    // Compute denominator
+    let mut denom = T::zero();
+    for &x in input {
+        denom = denom + T::exp(x - maxval);
+    }
+
+    for i in 0..input.len() {
+        output[i] = T::exp(input[i] - maxval) / denom;
+    }
+}
@@ -4,7 +4,10 @@
 //! Image classifier.
 
 use crate::{
-    architecture::Architecture,
+    architecture::{
+        Architecture,
+        Lift,
+    },
     network::{
         activation::Softmax,
         layer::{
@@ -15,7 +18,10 @@ use crate::{
         Layer,
     },
     Numeric,
-    optim::loss::CrossEntropyLoss,
+    optim::{
+        loss::CrossEntropyLoss,
+        Parameter,
+    },
     tensors::Batch,
 };
 
@@ -63,19 +69,47 @@ impl<const B: usize, T: Numeric, const C: usize> Architecture<B, T, 65536, C> fo
     fn forward(&mut self, batch: &Batch<B, T, 65536>) -> Batch<B, T, C> {
         let fmap1 = self.avgpool1.forward(&self.conv1.forward(batch));
         let fmap2 = self.avgpool2.forward(&self.conv2.forward(&fmap1));
-        
-        let mut out = T::zero();
-        for i in 0..fmap1.0[0].0.len() {
-            out = out + fmap1.0[0].0[i];
-        }
 
         self.fc1.forward(&fmap2)
     }
 
     /// Compute the backward pass of this regressor.
-    fn backward(&mut self, gradients: &Batch<B, T, C>, lr: T) {
-        let gmap2 = self.fc1.backward(gradients, lr);
-        let gmap1 = self.conv2.backward(&self.avgpool2.backward(&gmap2, lr), lr);
-        let _     = self.conv1.backward(&self.avgpool1.backward(&gmap1, lr), lr);
+    fn backward(&mut self, gradients: &Batch<B, T, C>) {
+        let gmap2 = self.fc1.backward(gradients);
+        let gmap1 = self.conv2.backward(&self.avgpool2.backward(&gmap2));
+        let _     = self.conv1.backward(&self.avgpool1.backward(&gmap1));
     }
+
+    /// Get the parameters of this classifier.
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        let mut parameters = self.conv1.parameters();
+        parameters.extend(self.avgpool1.parameters());
+        parameters.extend(self.conv2.parameters());
+        parameters.extend(self.avgpool2.parameters());
+        parameters.extend(self.fc1.parameters());
+        parameters
+    }
+
+    /// Get the buffers of this classifier.
+    fn buffers(&mut self) -> Vec<&mut [T]> {
+        let mut buffers = self.conv1.buffers();
+        buffers.extend(self.avgpool1.buffers());
+        buffers.extend(self.conv2.buffers());
+        buffers.extend(self.avgpool2.buffers());
+        buffers.extend(self.fc1.buffers());
+        buffers
+    }
+
+    /// Set the mode of this classifier.
+    fn set_training(&mut self, training: bool) {
+        self.conv1.set_training(training);
+        self.avgpool1.set_training(training);
+        self.conv2.set_training(training);
+        self.avgpool2.set_training(training);
+        self.fc1.set_training(training);
+    }
+}
+
+impl<const B: usize, T: Numeric, const C: usize> Lift<B, T, 65536, C> for ImageClassifier<B, T, C> {
+    type Lifted<U: Numeric> = ImageClassifier<B, U, C>;
 }
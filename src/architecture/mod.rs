@@ -12,7 +12,11 @@ use crate::{
     optim::{
         Hyperparameters,
         Loss,
+        Minimizer,
+        Optimizer,
+        Parameter,
     },
+    schedule::Scheduler,
     tensor::{
         Batch,
         Dataset,
@@ -39,25 +43,79 @@ pub trait Architecture<const B: usize, T: Numeric, const N: usize, const M: usiz
     /// Compute the forward pass of this model, returning an inference result.
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M>;
 
-    /// Evaluate this model, applying the given activation.
+    /// Evaluate this model in inference mode, applying the given activation.
+    /// 
+    /// NOTE this leaves the model in inference mode; see `Architecture::set_training`.
     fn eval(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.set_training(false);
         let mut activation = Self::Activation::new();
         activation.forward(&self.forward(batch))
     }
 
-    /// Compute the backward pass of this model, updating all parameters.
-    fn backward(&mut self, gradients: &Batch<B, T, M>, lr: T);
+    /// Compute the backward pass of this model, storing the gradients of all parameters.
+    fn backward(&mut self, gradients: &Batch<B, T, M>);
+
+    /// Get the trainable parameters of this model, along with their gradients
+    /// from the last backward pass.
+    /// 
+    /// NOTE parameters must be returned in the same order on every call.
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>>;
+
+    /// Get the number of trainable parameter values of this model.
+    /// 
+    /// NOTE this takes `&mut self` only because it counts the values returned by
+    /// `parameters`, which lends them out mutably; it does not modify this model.
+    fn parameter_count(&mut self) -> usize {
+        self.parameters().iter().map(|parameter| parameter.value.len()).sum()
+    }
+
+    /// Get the non-trainable state of this model which affects its output, such as
+    /// the running statistics of a `BatchNorm` layer.
+    /// 
+    /// NOTE buffers must be returned in the same order on every call.
+    fn buffers(&mut self) -> Vec<&mut [T]>;
+
+    /// Set whether every layer of this model is in training mode (the default) or
+    /// inference mode; see `alan::network::Layer::set_training`.
+    /// 
+    /// `Architecture::train` and `Architecture::minimize` enable training mode, while
+    /// `Architecture::eval` and `Architecture::test` enable inference mode.  The mode
+    /// is kept until it is set again, so later calls to `Architecture::forward` run
+    /// in the mode last set.
+    /// 
+    /// NOTE implementations must forward this to every layer, even layers which
+    /// currently behave the same in both modes.
+    fn set_training(&mut self, training: bool);
 
     /// Train this network on a given training set using the provided
     /// hyperparameters, and return the loss from each epoch.
+    /// 
+    /// The optimizer is selected by `hyperparameters.optimizer`.
     fn train(&mut self, dataset: &mut Dataset<B, T, N, M>, hyperparameters: Hyperparameters<T>) -> Vec<T> {
+        let mut optimizer = hyperparameters.optimizer.optimizer();
+        self.train_with(dataset, hyperparameters, optimizer.as_mut())
+    }
+
+    /// Train this network on a given training set using the provided
+    /// hyperparameters and optimizer, and return the loss from each epoch.
+    /// 
+    /// NOTE `hyperparameters.optimizer` is ignored in favor of `optimizer`.
+    fn train_with<O: Optimizer<T> + ?Sized>(&mut self, dataset: &mut Dataset<B, T, N, M>, hyperparameters: Hyperparameters<T>, optimizer: &mut O) -> Vec<T> {
+        self.set_training(true);
+
         // Instantiate loss function
         let mut loss_function = Self::LossFunction::new();
 
+        // Instantiate learning rate scheduler
+        let mut scheduler = Scheduler::new(hyperparameters.schedule, hyperparameters.lr);
+
         // Store losses
         let mut losses = Vec::new();
 
-        for _ in 0..hyperparameters.epochs {
+        for epoch in 0..hyperparameters.epochs {
+            // Learning rate for this epoch
+            let lr = scheduler.lr(epoch);
+
             // Total loss for this epoch
             let mut total_loss = T::zero();
 
@@ -71,11 +129,15 @@ pub trait Architecture<const B: usize, T: Numeric, const N: usize, const M: usiz
                 let gradients = loss_function.backward();
 
                 // Compute backward pass
-                self.backward(&gradients, hyperparameters.lr);
+                self.backward(&gradients);
+
+                // Update parameters
+                optimizer.step(&mut self.parameters(), lr);
             }
 
             // Store loss
             losses.push(total_loss);
+            scheduler.observe(total_loss);
 
             // Refresh dataset
             dataset.refresh();
@@ -84,8 +146,93 @@ pub trait Architecture<const B: usize, T: Numeric, const N: usize, const M: usiz
         losses
     }
 
-    /// Compute the loss over a test dataset.
+    /// Get the values of all parameters of this model, flattened into one vector.
+    fn flatten(&mut self) -> Vec<T> {
+        let mut values = Vec::new();
+        for parameter in self.parameters() {
+            values.extend_from_slice(parameter.value);
+        }
+        values
+    }
+
+    /// Set the values of all parameters of this model from a flattened vector,
+    /// as returned by `Architecture::flatten`.
+    fn unflatten(&mut self, values: &[T]) {
+        let mut offset = 0;
+        for parameter in self.parameters() {
+            let len = parameter.value.len();
+            parameter.value.copy_from_slice(&values[offset..offset+len]);
+            offset += len;
+        }
+    }
+
+    /// Get the gradients of all parameters of this model from the last backward pass,
+    /// flattened into one vector.
+    fn flatten_gradients(&mut self) -> Vec<T> {
+        let mut gradients = Vec::new();
+        for parameter in self.parameters() {
+            gradients.extend_from_slice(parameter.gradient);
+        }
+        gradients
+    }
+
+    /// Set the parameters of this model to `values` and compute the mean loss over
+    /// all batches of a dataset in training mode, along with its flattened gradient.
+    /// 
+    /// This function panics if `dataset` is empty.
+    fn loss_and_gradient(&mut self, dataset: &mut Dataset<B, T, N, M>, values: &[T]) -> (T, Vec<T>) {
+        self.set_training(true);
+        self.unflatten(values);
+
+        // Instantiate loss function
+        let mut loss_function = Self::LossFunction::new();
+
+        // Count number of batches
+        let mut batches_as_t = T::zero();
+
+        let mut total_loss = T::zero();
+        let mut total_gradient = vec![T::zero(); values.len()];
+
+        while let Some ((data, labels)) = dataset.next() {
+            // Compute forward and backward pass
+            let prediction = self.forward(&data);
+            total_loss = total_loss + loss_function.forward(&prediction, &labels);
+            self.backward(&loss_function.backward());
+
+            // Accumulate gradients
+            let gradient = self.flatten_gradients();
+            for i in 0..gradient.len() {
+                total_gradient[i] = total_gradient[i] + gradient[i];
+            }
+
+            batches_as_t = batches_as_t + T::one();
+        }
+        assert!(batches_as_t > T::zero(), "dataset must not be empty");
+
+        // Refresh dataset
+        dataset.refresh();
+
+        for gradient in total_gradient.iter_mut() {
+            *gradient = *gradient / batches_as_t;
+        }
+
+        (total_loss / batches_as_t, total_gradient)
+    }
+
+    /// Train this network on the full training set using the provided minimizer
+    /// (e.g. `alan::optim::LBFGS`), and return the final loss.
+    fn minimize<S: Minimizer<T>>(&mut self, dataset: &mut Dataset<B, T, N, M>, minimizer: &mut S) -> T {
+        let initial = self.flatten();
+        let values = minimizer.minimize(initial, |values| self.loss_and_gradient(dataset, values));
+        self.loss_and_gradient(dataset, &values).0
+    }
+
+    /// Compute the loss over a test dataset in inference mode.
+    /// 
+    /// NOTE this leaves the model in inference mode; see `Architecture::set_training`.
     fn test(&mut self, dataset: &mut Dataset<B, T, N, M>) -> T {
+        self.set_training(false);
+
         // Count number of batches
         let mut batches_as_t = T::zero();
 
@@ -110,3 +257,14 @@ pub trait Architecture<const B: usize, T: Numeric, const N: usize, const M: usiz
         total_loss / batches_as_t
     }
 }
+
+/// Network architecture which may be rebuilt over another `Numeric` type.
+/// 
+/// This allows an architecture trained over `T` to be evaluated over, e.g., `Dual<T>`
+/// with identical parameters, as in `alan::autodiff::hvp`.  Parameters and buffers of
+/// `Self` and of `Self::Lifted<U>` must be returned by `Architecture::parameters` and
+/// `Architecture::buffers` in the same order.
+pub trait Lift<const B: usize, T: Numeric, const N: usize, const M: usize>: Architecture<B, T, N, M> {
+    /// This architecture over the `Numeric` type `U`.
+    type Lifted<U: Numeric>: Architecture<B, U, N, M>;
+}
@@ -4,14 +4,20 @@
 //! Linear regressor.
 
 use crate::{
-    architecture::Architecture,
+    architecture::{
+        Architecture,
+        Lift,
+    },
     network::{
         activation::Identity,
         layer::Linear,
         Layer,
     },
     Numeric,
-    optim::loss::MSELoss,
+    optim::{
+        loss::MSELoss,
+        Parameter,
+    },
     tensors::Batch,
 };
 
@@ -41,7 +47,26 @@ impl<const B: usize, T: Numeric> Architecture<B, T, 1, 1> for LinearRegressor<B,
     }
 
     /// Compute the backward pass of this regressor.
-    fn backward(&mut self, gradients: &Batch<B, T, 1>, lr: T) {
-        self.linear_layer.backward(gradients, lr);
+    fn backward(&mut self, gradients: &Batch<B, T, 1>) {
+        self.linear_layer.backward(gradients);
+    }
+
+    /// Get the parameters of this regressor.
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        self.linear_layer.parameters()
+    }
+
+    /// Get the buffers of this regressor.
+    fn buffers(&mut self) -> Vec<&mut [T]> {
+        self.linear_layer.buffers()
+    }
+
+    /// Set the mode of this regressor.
+    fn set_training(&mut self, training: bool) {
+        self.linear_layer.set_training(training);
     }
 }
+
+impl<const B: usize, T: Numeric> Lift<B, T, 1, 1> for LinearRegressor<B, T> {
+    type Lifted<U: Numeric> = LinearRegressor<B, U>;
+}
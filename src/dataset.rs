@@ -3,13 +3,11 @@
 //! 
 //! Dataset abstraction.
 
-use rand::{
-    seq::SliceRandom,
-    rng,
-};
+use rand::seq::SliceRandom;
 
 use crate::{
     Numeric,
+    random::with_rng,
     tensor::{
         Batch,
         Tensor,
@@ -18,6 +16,9 @@ use crate::{
 
 #[derive(Clone, Debug)]
 /// Dataset for training or testing.
+/// 
+/// Batches are drawn from a random permutation of all samples, which is reshuffled by
+/// `Dataset::refresh`, so that each epoch yields every sample exactly once.
 pub struct Dataset<const B: usize, T: Numeric, const N: usize, const M: usize> {
     /// Data.
     data: Vec<Tensor<T, N>>,
@@ -25,7 +26,7 @@ pub struct Dataset<const B: usize, T: Numeric, const N: usize, const M: usize> {
     /// Labels.
     labels: Vec<Tensor<T, M>>,
 
-    /// Indices from which to yield.
+    /// Permutation of all sample indices, in the order in which to yield them.
     shuffle: Vec<usize>,
 
     /// Number of batches yielded so far.
@@ -38,14 +39,11 @@ impl<const B: usize, T: Numeric, const N: usize, const M: usize> Dataset<B, T, N
     /// This function returns `Option<Dataset<B, T, N, M>>`.  If `data` and `labels` are
     /// different lengths, the function returns `None`.
     pub fn new(data: Vec<Tensor<T, N>>, labels: Vec<Tensor<T, M>>) -> Option<Self> {
-        if data.len() != labels.len() {
-            None
-        } else if data.len() % B != 0 {
+        if data.len() != labels.len() || !data.len().is_multiple_of(B) {
             None
         } else {
-            let mut rng = rng();
-            let mut shuffle: Vec<usize> = (0..B).collect();
-            shuffle.shuffle(&mut rng);
+            let mut shuffle: Vec<usize> = (0..data.len()).collect();
+            with_rng(|rng| shuffle.shuffle(rng));
 
             Some (Self {
                 data,
@@ -57,32 +55,49 @@ impl<const B: usize, T: Numeric, const N: usize, const M: usize> Dataset<B, T, N
     }
 
     /// Yield the next batch from this dataset, if it is available.
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<(Batch<B, T, N>, Batch<B, T, M>)> {
         // Do we have enough for a batch?
         if B*(self.batch+1) > self.data.len() {
             None
         } else {
-            let mut thisdata = [Tensor::zero(); B];
-            let mut thislabel = [Tensor::zero(); B];
+            let mut thisdata = Batch::zero();
+            let mut thislabel = Batch::zero();
             for (e, i) in (B*self.batch..B*(self.batch+1)).enumerate() {
-                thisdata[e] = self.data[self.shuffle[i]];
-                thislabel[e] = self.labels[self.shuffle[i]];
+                thisdata[e] = self.data[self.shuffle[i]].clone();
+                thislabel[e] = self.labels[self.shuffle[i]].clone();
             }
             self.batch += 1;
 
-            Some ((Batch (thisdata), Batch (thislabel)))
+            Some ((thisdata, thislabel))
         }
     } 
 
-    /// Refresh this dataset.
+    /// Refresh this dataset, reshuffling all samples and restarting from the first batch.
     pub fn refresh(&mut self) {
         // Create new random ordering
-        let mut rng = rng();
-        let mut shuffle: Vec<usize> = (0..B).collect();
-        shuffle.shuffle(&mut rng);
-        self.shuffle = shuffle;
+        with_rng(|rng| self.shuffle.shuffle(rng));
 
         // Reset batch number
         self.batch = 0;
     }
 }
+
+#[test]
+fn test_dataset_batches() {
+    let data: Vec<Tensor<f64, 1>> = (0..6).map(|i| Tensor::new([i as f64])).collect();
+    let labels = data.clone();
+    let mut dataset = Dataset::<2, f64, 1, 1>::new(data, labels).unwrap();
+
+    // Every sample is yielded exactly once per epoch, with its own label
+    for _ in 0..2 {
+        let mut seen = Vec::new();
+        while let Some ((data, labels)) = dataset.next() {
+            assert_eq!(data, labels);
+            seen.extend(data.iter().map(|x| x[0] as usize));
+        }
+        seen.sort();
+        assert_eq!(seen, vec![0, 1, 2, 3, 4, 5]);
+        dataset.refresh();
+    }
+}
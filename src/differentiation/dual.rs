@@ -0,0 +1,232 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Forward-mode automatic differentiation with dual numbers.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{
+        Add,
+        Div,
+        Mul,
+        Neg,
+        Sub,
+    },
+};
+
+use crate::{
+    Numeric,
+    tensor::Batch,
+};
+
+/// Dual number.
+/// 
+/// A `Dual<T>` carries a value along with its tangent, i.e. its derivative along
+/// some direction.  Because `Dual` is `Numeric`, any generic network code evaluated
+/// over `Dual`s computes directional derivatives alongside its usual output.
+#[derive(Clone, Copy)]
+pub struct Dual<T: Numeric> {
+    /// Value.
+    pub value: T,
+
+    /// Tangent.
+    pub tangent: T,
+}
+
+impl<T: Numeric> Dual<T> {
+    /// Construct a new dual number.
+    pub fn new(value: T, tangent: T) -> Self {
+        Self {
+            value,
+            tangent,
+        }
+    }
+
+    /// Construct a new constant, with zero tangent.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+}
+
+/// Compute the Jacobian-vector product of `f` at `x` along `v`.
+/// 
+/// This function returns `(f(x), J v)`, where `J` is the Jacobian of `f` at `x`.
+pub fn jvp<const B: usize, T: Numeric, const N: usize, const M: usize, F>(f: F, x: &Batch<B, T, N>, v: &Batch<B, T, N>) -> (Batch<B, T, M>, Batch<B, T, M>)
+where
+    F: FnOnce(&Batch<B, Dual<T>, N>) -> Batch<B, Dual<T>, M>,
+{
+    let mut input = Batch::<B, Dual<T>, N>::zero();
+    for b in 0..B {
+        for i in 0..N {
+            input[b][i] = Dual::new(x[b][i], v[b][i]);
+        }
+    }
+
+    let output = f(&input);
+
+    let mut value = Batch::<B, T, M>::zero();
+    let mut tangent = Batch::<B, T, M>::zero();
+    for b in 0..B {
+        for i in 0..M {
+            value[b][i] = output[b][i].value;
+            tangent[b][i] = output[b][i].tangent;
+        }
+    }
+
+    (value, tangent)
+}
+
+impl<T: Numeric> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.value + other.value, self.tangent + other.tangent)
+    }
+}
+
+impl<T: Numeric> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.value - other.value, self.tangent - other.tangent)
+    }
+}
+
+impl<T: Numeric> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.value * other.value, self.tangent * other.value + self.value * other.tangent)
+    }
+}
+
+impl<T: Numeric> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let value = self.value / other.value;
+        Self::new(value, (self.tangent - value * other.tangent) / other.value)
+    }
+}
+
+impl<T: Numeric> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.tangent)
+    }
+}
+
+impl<T: Numeric> PartialEq for Dual<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Numeric> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Numeric> fmt::Debug for Dual<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} + {:?}e", self.value, self.tangent)
+    }
+}
+
+impl<T: Numeric> Numeric for Dual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        Self::new(value, self.tangent * value)
+    }
+
+    fn log(self) -> Self {
+        Self::new(self.value.log(), self.tangent / self.value)
+    }
+
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        Self::new(value, self.tangent / (value + value))
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+
+    fn random() -> Self {
+        Self::constant(T::random())
+    }
+
+    fn tiny() -> Self {
+        Self::constant(T::tiny())
+    }
+
+    fn neginf() -> Self {
+        Self::constant(T::neginf())
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    loss::{
+        CrossEntropyLoss,
+        Loss,
+    },
+    tensor::Tensor,
+};
+
+#[test]
+fn test_forward_mode() {
+    let x = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([0.5, -1.0, 2.0]),
+        Tensor::<f64, 3>::new([1.5, 0.0, -0.5]),
+    ]);
+    let v = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([1.0, 2.0, -1.0]),
+        Tensor::<f64, 3>::new([0.0, -3.0, 1.0]),
+    ]);
+    let labels = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([0.0, 0.0, 1.0]),
+        Tensor::<f64, 3>::new([1.0, 0.0, 0.0]),
+    ]);
+
+    // Directional derivative of the cross-entropy loss
+    let mut dual_labels = Batch::<2, Dual<f64>, 3>::zero();
+    for b in 0..2 {
+        for i in 0..3 {
+            dual_labels[b][i] = Dual::constant(labels[b][i]);
+        }
+    }
+    let (_, tangent) = jvp::<2, f64, 3, 1, _>(|x| {
+        let mut loss = Batch::zero();
+        loss[0][0] = CrossEntropyLoss::new().forward(x, &dual_labels);
+        loss
+    }, &x, &v);
+
+    // Compare against the hand-written backward pass, which returns
+    // the gradient of each sample's loss
+    let mut loss = CrossEntropyLoss::<2, f64, 3>::new();
+    loss.forward(&x, &labels);
+    let gradients = loss.backward();
+    let mut expected = 0.0;
+    for b in 0..2 {
+        for i in 0..3 {
+            expected += gradients[b][i] * v[b][i] / 2.0;
+        }
+    }
+    assert!((tangent[0][0] - expected).abs() < 1e-12);
+}
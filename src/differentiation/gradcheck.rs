@@ -0,0 +1,249 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Finite-difference gradient checking.
+
+use crate::{
+    network::{
+        Activation,
+        Layer,
+    },
+    Numeric,
+    optim::Loss,
+    tensor::Batch,
+};
+
+#[derive(Clone, Debug)]
+/// Result of a gradient check.
+/// 
+/// Each error is the maximum relative error `|a - n| / max(|a|, |n|)` between an
+/// analytical gradient `a` (from a backward pass) and the corresponding numerical
+/// gradient `n` (from central finite differences).
+pub struct GradCheck<T: Numeric> {
+    /// Maximum relative error of input gradients.
+    pub input: T,
+
+    /// Maximum relative error of parameter gradients.
+    pub parameters: T,
+
+    /// Analytical and numerical value of every gradient checked, input gradients first.
+    pub gradients: Vec<(T, T)>,
+}
+
+impl<T: Numeric> GradCheck<T> {
+    /// Maximum relative error over all gradients.
+    pub fn max(&self) -> T {
+        if self.input > self.parameters {
+            self.input
+        } else {
+            self.parameters
+        }
+    }
+
+    /// Do all gradients agree to within `atol + rtol * max(|a|, |n|)`?
+    /// 
+    /// Central differences of a gradient near zero are dominated by rounding error in the
+    /// objective, so their relative error is meaningless; the absolute tolerance `atol`
+    /// bounds the error of such gradients instead.
+    pub fn allclose(&self, rtol: T, atol: T) -> bool {
+        self.gradients.iter().all(|&(analytical, numerical)| {
+            let scale = maximum(abs(analytical), abs(numerical));
+            let error = abs(analytical - numerical);
+            error <= atol + rtol * scale
+        })
+    }
+}
+
+/// Check the gradients of a layer at the given input against central finite
+/// differences with step `epsilon`.
+/// 
+/// The layer output is weighted by a random upstream gradient, so that backward
+/// passes which fail to chain their upstream gradient are caught.
+/// 
+/// NOTE the layer must be deterministic (e.g. no dropout) for this check to be meaningful.
+pub fn gradcheck<const B: usize, T: Numeric, const N: usize, const M: usize, L: Layer<B, T, N, M>>(layer: &mut L, input: &Batch<B, T, N>, epsilon: T) -> GradCheck<T> {
+    let weights = random_batch::<B, T, M>();
+    let objective = |layer: &mut L, input: &Batch<B, T, N>| weighted_sum(&weights, &layer.forward(input));
+
+    // Analytical gradients
+    layer.forward(input);
+    let input_gradients = layer.backward(&weights);
+    let parameter_gradients: Vec<Vec<T>> = layer.parameters().iter().map(|p| p.gradient.to_vec()).collect();
+
+    // Numerical input gradients
+    let mut pairs = Vec::new();
+    let mut input_error = T::zero();
+    let mut perturbed = input.clone();
+    for b in 0..B {
+        for i in 0..N {
+            perturbed[b][i] = input[b][i] + epsilon;
+            let plus = objective(layer, &perturbed);
+            perturbed[b][i] = input[b][i] - epsilon;
+            let minus = objective(layer, &perturbed);
+            perturbed[b][i] = input[b][i];
+
+            let numerical = (plus - minus) / (epsilon + epsilon);
+            input_error = maximum(input_error, relative_error(input_gradients[b][i], numerical));
+            pairs.push((input_gradients[b][i], numerical));
+        }
+    }
+
+    // Numerical parameter gradients
+    // NOTE layers average parameter gradients over the batch
+    let b_as_t = count::<T>(B);
+    let mut parameter_error = T::zero();
+    for (p, gradients) in parameter_gradients.iter().enumerate() {
+        for (k, &gradient) in gradients.iter().enumerate() {
+            let value = layer.parameters()[p].value[k];
+
+            layer.parameters()[p].value[k] = value + epsilon;
+            let plus = objective(layer, input);
+            layer.parameters()[p].value[k] = value - epsilon;
+            let minus = objective(layer, input);
+            layer.parameters()[p].value[k] = value;
+
+            let numerical = (plus - minus) / (epsilon + epsilon);
+            parameter_error = maximum(parameter_error, relative_error(gradient * b_as_t, numerical));
+            pairs.push((gradient * b_as_t, numerical));
+        }
+    }
+
+    // Restore layer state
+    layer.forward(input);
+
+    GradCheck {
+        input: input_error,
+        parameters: parameter_error,
+        gradients: pairs,
+    }
+}
+
+/// Check the gradients of an activation function at the given input against central
+/// finite differences with step `epsilon`.
+pub fn gradcheck_activation<const B: usize, T: Numeric, const N: usize, A: Activation<B, T, N>>(activation: &mut A, input: &Batch<B, T, N>, epsilon: T) -> GradCheck<T> {
+    let weights = random_batch::<B, T, N>();
+
+    // Analytical gradients
+    activation.forward(input);
+    let input_gradients = activation.backward(&weights);
+
+    // Numerical gradients
+    let mut pairs = Vec::new();
+    let mut input_error = T::zero();
+    let mut perturbed = input.clone();
+    for b in 0..B {
+        for i in 0..N {
+            perturbed[b][i] = input[b][i] + epsilon;
+            let plus = weighted_sum(&weights, &activation.forward(&perturbed));
+            perturbed[b][i] = input[b][i] - epsilon;
+            let minus = weighted_sum(&weights, &activation.forward(&perturbed));
+            perturbed[b][i] = input[b][i];
+
+            let numerical = (plus - minus) / (epsilon + epsilon);
+            input_error = maximum(input_error, relative_error(input_gradients[b][i], numerical));
+            pairs.push((input_gradients[b][i], numerical));
+        }
+    }
+
+    // Restore activation state
+    activation.forward(input);
+
+    GradCheck {
+        input: input_error,
+        parameters: T::zero(),
+        gradients: pairs,
+    }
+}
+
+/// Check the gradients of a loss function at the given prediction and labels against
+/// central finite differences with step `epsilon`.
+pub fn gradcheck_loss<const B: usize, T: Numeric, const N: usize, L: Loss<B, T, N>>(loss: &mut L, prediction: &Batch<B, T, N>, labels: &Batch<B, T, N>, epsilon: T) -> GradCheck<T> {
+    // Analytical gradients
+    // NOTE losses return the gradient of each sample's loss, whereas the loss
+    // itself is averaged over the batch
+    loss.forward(prediction, labels);
+    let gradients = loss.backward();
+    let b_as_t = count::<T>(B);
+
+    // Numerical gradients
+    let mut pairs = Vec::new();
+    let mut input_error = T::zero();
+    let mut perturbed = prediction.clone();
+    for b in 0..B {
+        for i in 0..N {
+            perturbed[b][i] = prediction[b][i] + epsilon;
+            let plus = loss.forward(&perturbed, labels);
+            perturbed[b][i] = prediction[b][i] - epsilon;
+            let minus = loss.forward(&perturbed, labels);
+            perturbed[b][i] = prediction[b][i];
+
+            let numerical = (plus - minus) / (epsilon + epsilon);
+            input_error = maximum(input_error, relative_error(gradients[b][i] / b_as_t, numerical));
+            pairs.push((gradients[b][i] / b_as_t, numerical));
+        }
+    }
+
+    // Restore loss state
+    loss.forward(prediction, labels);
+
+    GradCheck {
+        input: input_error,
+        parameters: T::zero(),
+        gradients: pairs,
+    }
+}
+
+/// Random batch of upstream gradients.
+fn random_batch<const B: usize, T: Numeric, const N: usize>() -> Batch<B, T, N> {
+    let mut batch = Batch::zero();
+    for b in 0..B {
+        for i in 0..N {
+            batch[b][i] = T::random();
+        }
+    }
+    batch
+}
+
+/// Sum of `outputs`, weighted elementwise by `weights`.
+fn weighted_sum<const B: usize, T: Numeric, const N: usize>(weights: &Batch<B, T, N>, outputs: &Batch<B, T, N>) -> T {
+    let mut total = T::zero();
+    for b in 0..B {
+        for i in 0..N {
+            total = total + weights[b][i] * outputs[b][i];
+        }
+    }
+    total
+}
+
+/// Relative error between two values.
+fn relative_error<T: Numeric>(analytical: T, numerical: T) -> T {
+    let scale = maximum(maximum(abs(analytical), abs(numerical)), T::tiny());
+    abs(analytical - numerical) / scale
+}
+
+/// Absolute value.
+fn abs<T: Numeric>(x: T) -> T {
+    if x < T::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Larger of two values.
+fn maximum<T: Numeric>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Count as `T`.
+fn count<T: Numeric>(n: usize) -> T {
+    let mut result = T::zero();
+    for _ in 0..n {
+        result = result + T::one();
+    }
+    result
+}
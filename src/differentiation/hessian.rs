@@ -0,0 +1,282 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Second derivatives of architecture losses.
+
+use crate::{
+    differentiation::Dual,
+    models::{
+        Architecture,
+        Lift,
+    },
+    Numeric,
+    optim::Loss,
+    tensor::Batch,
+};
+
+/// Compute the product of the Hessian of the loss of an architecture on one batch with
+/// the vector `v`, at the current parameters.
+/// 
+/// The architecture is rebuilt over `Dual<T>` with parameter tangents `v`, so that its
+/// (hand-written) backward pass computes the gradient of the loss along with its
+/// derivative along `v`, i.e. the Hessian-vector product.  `v` is laid out as in
+/// `Architecture::flatten`, and must have one entry per parameter value.
+/// 
+/// NOTE the rebuilt architecture runs in inference mode, with the buffers of
+/// `architecture` (see `Architecture::buffers`), so that the result is deterministic
+/// whatever the mode of `architecture`.  For example, `Dropout` is disabled and
+/// `BatchNorm` uses its running statistics.  Layer options which are neither
+/// parameters nor buffers are those set by `Architecture::new`.
+pub fn hvp<const B: usize, T: Numeric, const N: usize, const M: usize, A: Lift<B, T, N, M>>(architecture: &mut A, batch: &Batch<B, T, N>, labels: &Batch<B, T, M>, v: &[T]) -> Vec<T> {
+    let values = architecture.flatten();
+    assert_eq!(v.len(), values.len(), "v must have one entry per parameter value");
+    let mut lifted = lift_architecture(architecture);
+    lifted.unflatten(&(0..values.len()).map(|i| Dual::new(values[i], v[i])).collect::<Vec<Dual<T>>>());
+
+    hvp_lifted(&mut lifted, &lift(batch), &lift(labels))
+}
+
+/// Compute the Hessian of the loss of an architecture on one batch, at the current
+/// parameters.
+/// 
+/// This function returns the Hessian as a list of rows, each computed with `hvp`, so
+/// the same NOTE on inference mode applies.  It is intended for small models.
+pub fn hessian<const B: usize, T: Numeric, const N: usize, const M: usize, A: Lift<B, T, N, M>>(architecture: &mut A, batch: &Batch<B, T, N>, labels: &Batch<B, T, M>) -> Vec<Vec<T>> {
+    unit_products(architecture, batch, labels).collect()
+}
+
+/// Compute the second derivative of the loss of an architecture on one batch with
+/// respect to each parameter, at the current parameters.
+/// 
+/// This function returns the diagonal of `hessian`, laid out as in `Architecture::flatten`,
+/// keeping only one row of the Hessian at a time.
+pub fn hessian_diagonal<const B: usize, T: Numeric, const N: usize, const M: usize, A: Lift<B, T, N, M>>(architecture: &mut A, batch: &Batch<B, T, N>, labels: &Batch<B, T, M>) -> Vec<T> {
+    unit_products(architecture, batch, labels)
+        .enumerate()
+        .map(|(i, row)| row[i])
+        .collect()
+}
+
+/// Iterate over the products of the Hessian of the loss of an architecture on one
+/// batch with each unit vector, i.e. over the rows of the (symmetric) Hessian.
+fn unit_products<const B: usize, T: Numeric, const N: usize, const M: usize, A: Lift<B, T, N, M>>(architecture: &mut A, batch: &Batch<B, T, N>, labels: &Batch<B, T, M>) -> impl Iterator<Item = Vec<T>> {
+    let values = architecture.flatten();
+    let mut lifted = lift_architecture(architecture);
+    let batch = lift(batch);
+    let labels = lift(labels);
+
+    (0..values.len()).map(move |i| {
+        lifted.unflatten(&(0..values.len()).map(|j| Dual::new(values[j], unit(i, j))).collect::<Vec<Dual<T>>>());
+        hvp_lifted(&mut lifted, &batch, &labels)
+    })
+}
+
+/// Rebuild an architecture over `Dual<T>` in inference mode, with constant copies of
+/// its buffers.
+fn lift_architecture<const B: usize, T: Numeric, const N: usize, const M: usize, A: Lift<B, T, N, M>>(architecture: &mut A) -> A::Lifted<Dual<T>> {
+    let mut lifted = A::Lifted::<Dual<T>>::new();
+    lifted.set_training(false);
+
+    let mut buffers = lifted.buffers();
+    let sources = architecture.buffers();
+    assert_eq!(buffers.len(), sources.len(), "lifted architecture must have the same buffers");
+    for (buffer, source) in buffers.iter_mut().zip(sources) {
+        for (value, &source) in buffer.iter_mut().zip(source.iter()) {
+            *value = Dual::constant(source);
+        }
+    }
+
+    lifted
+}
+
+/// Compute the tangents of the loss gradient of an architecture over `Dual<T>`.
+fn hvp_lifted<const B: usize, T: Numeric, const N: usize, const M: usize, A: Architecture<B, Dual<T>, N, M>>(lifted: &mut A, batch: &Batch<B, Dual<T>, N>, labels: &Batch<B, Dual<T>, M>) -> Vec<T> {
+    let mut loss_function = A::LossFunction::new();
+    let prediction = lifted.forward(batch);
+    loss_function.forward(&prediction, labels);
+    lifted.backward(&loss_function.backward());
+
+    lifted.flatten_gradients().into_iter()
+        .map(|g| g.tangent)
+        .collect()
+}
+
+/// Lift a batch to constant `Dual`s.
+fn lift<const B: usize, T: Numeric, const N: usize>(batch: &Batch<B, T, N>) -> Batch<B, Dual<T>, N> {
+    let mut result = Batch::zero();
+    for b in 0..B {
+        for i in 0..N {
+            result[b][i] = Dual::constant(batch[b][i]);
+        }
+    }
+    result
+}
+
+/// Component `j` of the `i`th unit vector.
+fn unit<T: Numeric>(i: usize, j: usize) -> T {
+    if i == j {
+        T::one()
+    } else {
+        T::zero()
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    models::regressors::LinearRegressor,
+    network::{
+        activation::Identity,
+        Layer,
+        layer::{
+            BatchNorm,
+            Dropout,
+            Linear,
+        },
+    },
+    optim::{
+        loss::MSELoss,
+        Parameter,
+    },
+    tensor::Tensor,
+};
+
+/// Linear regressor with batch normalization and dropout on its input.
+#[cfg(test)]
+struct NormalizedRegressor<const B: usize, T: Numeric> {
+    batchnorm: BatchNorm<B, T, 1, 1>,
+    dropout: Dropout<B, T, 1, 50>,
+    linear: Linear<B, T, 1, 1>,
+}
+
+#[cfg(test)]
+impl<const B: usize, T: Numeric> Architecture<B, T, 1, 1> for NormalizedRegressor<B, T> {
+    type LossFunction = MSELoss<B, T, 1>;
+
+    type Activation = Identity<1>;
+
+    fn new() -> Self {
+        Self {
+            batchnorm: BatchNorm::new(),
+            dropout: Dropout::new(),
+            linear: Linear::new(),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, 1>) -> Batch<B, T, 1> {
+        self.linear.forward(&self.dropout.forward(&self.batchnorm.forward(batch)))
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, 1>) {
+        self.batchnorm.backward(&self.dropout.backward(&self.linear.backward(gradients)));
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        let mut parameters = self.batchnorm.parameters();
+        parameters.extend(self.linear.parameters());
+        parameters
+    }
+
+    fn buffers(&mut self) -> Vec<&mut [T]> {
+        let mut buffers = self.batchnorm.buffers();
+        buffers.extend(self.linear.buffers());
+        buffers
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.batchnorm.set_training(training);
+        self.dropout.set_training(training);
+        self.linear.set_training(training);
+    }
+}
+
+#[cfg(test)]
+impl<const B: usize, T: Numeric> Lift<B, T, 1, 1> for NormalizedRegressor<B, T> {
+    type Lifted<U: Numeric> = NormalizedRegressor<B, U>;
+}
+
+#[test]
+fn test_hessian() {
+    let mut model = LinearRegressor::<3, f64>::new();
+    let batch = Batch::<3, f64, 1> ([
+        Tensor::<f64, 1>::new([1.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([-4.0]),
+    ]);
+    let labels = Batch::<3, f64, 1> ([
+        Tensor::<f64, 1>::new([0.5]),
+        Tensor::<f64, 1>::new([3.0]),
+        Tensor::<f64, 1>::new([-1.0]),
+    ]);
+
+    // Mean squared error of `w * x + c` has Hessian `2/B * sum [[x^2, x], [x, 1]]`
+    let expected = [
+        [2.0 * 21.0 / 3.0, -2.0 / 3.0],
+        [-2.0 / 3.0, 2.0],
+    ];
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+    let h = hessian(&mut model, &batch, &labels);
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!(close(h[i][j], expected[i][j]));
+        }
+    }
+
+    let hv = hvp(&mut model, &batch, &labels, &[1.0, -2.0]);
+    assert!(close(hv[0], expected[0][0] - 2.0 * expected[0][1]));
+    assert!(close(hv[1], expected[1][0] - 2.0 * expected[1][1]));
+
+    let diagonal = hessian_diagonal(&mut model, &batch, &labels);
+    assert!(close(diagonal[0], expected[0][0]));
+    assert!(close(diagonal[1], expected[1][1]));
+}
+
+#[test]
+#[should_panic(expected = "v must have one entry per parameter value")]
+fn test_hvp_length() {
+    let mut model = LinearRegressor::<1, f64>::new();
+    let batch = Batch::<1, f64, 1> ([Tensor::<f64, 1>::new([1.0])]);
+
+    // Linear regressor has two parameter values
+    hvp(&mut model, &batch, &batch, &[1.0]);
+}
+
+#[test]
+fn test_hvp_inference_mode() {
+    let mut model = NormalizedRegressor::<3, f64>::new();
+    model.batchnorm.running_mean = [1.5];
+    model.batchnorm.running_var = [4.0];
+    let batch = Batch::<3, f64, 1> ([
+        Tensor::<f64, 1>::new([1.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([-4.0]),
+    ]);
+    let labels = Batch::<3, f64, 1> ([
+        Tensor::<f64, 1>::new([0.5]),
+        Tensor::<f64, 1>::new([3.0]),
+        Tensor::<f64, 1>::new([-1.0]),
+    ]);
+    let v = [1.0, -2.0, 0.5, 3.0];
+
+    // Dropout is disabled, so repeated products agree
+    let hv = hvp(&mut model, &batch, &labels, &v);
+    assert_eq!(hv, hvp(&mut model, &batch, &labels, &v));
+
+    // Central difference along `v` of the gradient in inference mode, which uses
+    // the running statistics of `model`
+    let values = model.flatten();
+    let epsilon = 1e-6;
+    let mut gradient = |sign: f64| {
+        model.unflatten(&values.iter().zip(v.iter()).map(|(x, v)| x + sign * epsilon * v).collect::<Vec<f64>>());
+        model.set_training(false);
+        let mut loss_function = MSELoss::new();
+        let prediction = model.forward(&batch);
+        loss_function.forward(&prediction, &labels);
+        model.backward(&loss_function.backward());
+        model.flatten_gradients()
+    };
+    let (plus, minus) = (gradient(1.0), gradient(-1.0));
+    for ((plus, minus), hv) in plus.iter().zip(minus.iter()).zip(hv.iter()) {
+        assert!(((plus - minus) / (2.0 * epsilon) - hv).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,49 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Automatic differentiation.
+
+mod dual;
+mod gradcheck;
+mod hessian;
+mod tape;
+
+use crate::{
+    Numeric,
+    tensor::Tensor,
+};
+
+pub use dual::{
+    Dual,
+    jvp,
+};
+pub use gradcheck::{
+    GradCheck,
+    gradcheck,
+    gradcheck_activation,
+    gradcheck_loss,
+};
+pub use hessian::{
+    hessian,
+    hessian_diagonal,
+    hvp,
+};
+pub use tape::{
+    Gradients,
+    Graph,
+    Var,
+};
+
+/// Differentiable function with trainable parameters, defined by its forward pass only.
+/// 
+/// A `Function<N, M>` maps `Tensor`s of size `N` to `Tensor`s of size `M` using
+/// `PARAMETERS` trainable parameters.  Because `Function::forward` is generic over
+/// the `Numeric` type, it may be evaluated over `Var`s to compute gradients on a tape.
+/// See `alan::network::layer::Autograd`.
+pub trait Function<const N: usize, const M: usize> {
+    /// Number of trainable parameters.
+    const PARAMETERS: usize;
+
+    /// Compute the output of this function for one input.
+    fn forward<V: Numeric>(parameters: &[V], input: &Tensor<V, N>) -> Tensor<V, M>;
+}
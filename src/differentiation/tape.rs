@@ -0,0 +1,299 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Reverse-mode automatic differentiation tape.
+
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    fmt,
+    ops::{
+        Add,
+        Div,
+        Mul,
+        Neg,
+        Sub,
+    },
+};
+
+use crate::Numeric;
+
+/// Operation recorded on a tape.
+/// 
+/// Each node stores up to two parents, along with the partial derivative of the
+/// node value with respect to each parent.
+#[derive(Clone, Copy, Debug)]
+struct Node<T: Numeric> {
+    parents: [Option<(usize, T)>; 2],
+}
+
+/// Tape recording operations on `Var`s for reverse-mode differentiation.
+pub struct Graph<T: Numeric> {
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+impl<T: Numeric> Graph<T> {
+    /// Construct a new, empty tape.
+    pub fn new() -> Self {
+        Self {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Create a new leaf variable on this tape.
+    pub fn var(&self, value: T) -> Var<'_, T> {
+        let index = self.push(Node {parents: [None, None]});
+        Var {
+            graph: Some (self),
+            index,
+            value,
+        }
+    }
+
+    /// Number of nodes recorded on this tape.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    /// Has nothing been recorded on this tape?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Record a node, returning its index.
+    fn push(&self, node: Node<T>) -> usize {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(node);
+        nodes.len() - 1
+    }
+}
+
+impl<T: Numeric> Default for Graph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Differentiable variable.
+/// 
+/// A `Var` is either recorded on a `Graph` or a constant.  Constants (such as
+/// `Var::zero()` or `Var::constant(x)`) are not recorded and have zero gradient.
+/// Because `Var` is `Numeric`, any generic network code may be evaluated over
+/// `Var`s and differentiated with `Var::backward`.
+/// 
+/// NOTE variables recorded on different tapes must not be combined.
+#[derive(Clone, Copy)]
+pub struct Var<'g, T: Numeric> {
+    /// Tape on which this variable is recorded, if any.
+    graph: Option<&'g Graph<T>>,
+
+    /// Index of this variable on its tape.
+    index: usize,
+
+    /// Value of this variable.
+    value: T,
+}
+
+impl<'g, T: Numeric> Var<'g, T> {
+    /// Construct a new constant.
+    pub fn constant(value: T) -> Self {
+        Self {
+            graph: None,
+            index: 0,
+            value,
+        }
+    }
+
+    /// Get the value of this variable.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Compute the gradient of this variable with respect to every variable
+    /// recorded before it on its tape.
+    pub fn backward(&self) -> Gradients<T> {
+        let graph = match self.graph {
+            Some (graph) => graph,
+            None => return Gradients (Vec::new()),
+        };
+        let nodes = graph.nodes.borrow();
+
+        // Propagate adjoints from this variable back to the leaves
+        let mut adjoints = vec![T::zero(); self.index + 1];
+        adjoints[self.index] = T::one();
+        for i in (0..=self.index).rev() {
+            for (parent, partial) in nodes[i].parents.iter().flatten() {
+                adjoints[*parent] = adjoints[*parent] + adjoints[i] * *partial;
+            }
+        }
+
+        Gradients (adjoints)
+    }
+
+    /// Record the result of a unary operation with the given partial derivative.
+    fn unary(self, value: T, partial: T) -> Self {
+        match self.graph {
+            Some (graph) => Self {
+                graph: Some (graph),
+                index: graph.push(Node {parents: [Some ((self.index, partial)), None]}),
+                value,
+            },
+            None => Self::constant(value),
+        }
+    }
+
+    /// Record the result of a binary operation with the given partial derivatives.
+    fn binary(self, other: Self, value: T, partial: T, other_partial: T) -> Self {
+        let parent = self.graph.map(|_| (self.index, partial));
+        let other_parent = other.graph.map(|_| (other.index, other_partial));
+        match self.graph.or(other.graph) {
+            Some (graph) => Self {
+                graph: Some (graph),
+                index: graph.push(Node {parents: [parent, other_parent]}),
+                value,
+            },
+            None => Self::constant(value),
+        }
+    }
+}
+
+/// Gradients computed by `Var::backward`.
+pub struct Gradients<T: Numeric> (Vec<T>);
+
+impl<T: Numeric> Gradients<T> {
+    /// Get the gradient with respect to the given variable.
+    /// 
+    /// This is zero for constants and variables recorded after the differentiated one.
+    pub fn wrt(&self, var: &Var<'_, T>) -> T {
+        match var.graph {
+            Some (_) if var.index < self.0.len() => self.0[var.index],
+            _ => T::zero(),
+        }
+    }
+}
+
+impl<T: Numeric> Add for Var<'_, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.binary(other, self.value + other.value, T::one(), T::one())
+    }
+}
+
+impl<T: Numeric> Sub for Var<'_, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.binary(other, self.value - other.value, T::one(), -T::one())
+    }
+}
+
+impl<T: Numeric> Mul for Var<'_, T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.binary(other, self.value * other.value, other.value, self.value)
+    }
+}
+
+impl<T: Numeric> Div for Var<'_, T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let value = self.value / other.value;
+        self.binary(other, value, T::one() / other.value, -value / other.value)
+    }
+}
+
+impl<T: Numeric> Neg for Var<'_, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.unary(-self.value, -T::one())
+    }
+}
+
+impl<T: Numeric> PartialEq for Var<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Numeric> PartialOrd for Var<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Numeric> fmt::Debug for Var<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Var({:?})", self.value)
+    }
+}
+
+impl<T: Numeric> Numeric for Var<'_, T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.unary(value, value)
+    }
+
+    fn log(self) -> Self {
+        self.unary(self.value.log(), T::one() / self.value)
+    }
+
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        self.unary(value, T::one() / (value + value))
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+
+    fn random() -> Self {
+        Self::constant(T::random())
+    }
+
+    fn tiny() -> Self {
+        Self::constant(T::tiny())
+    }
+
+    fn neginf() -> Self {
+        Self::constant(T::neginf())
+    }
+}
+
+#[test]
+fn test_reverse_mode() {
+    let graph = Graph::new();
+    let x = graph.var(2.0f64);
+    let y = graph.var(3.0f64);
+
+    // z = x * y + exp(x) / y - log(y) + sqrt(x)
+    let z = x * y + x.exp() / y - y.log() + x.sqrt();
+    let gradients = z.backward();
+
+    let dzdx = 3.0 + 2.0f64.exp() / 3.0 + 0.5 / 2.0f64.sqrt();
+    let dzdy = 2.0 - 2.0f64.exp() / 9.0 - 1.0 / 3.0;
+    assert!((gradients.wrt(&x) - dzdx).abs() < 1e-12);
+    assert!((gradients.wrt(&y) - dzdy).abs() < 1e-12);
+
+    // Constants have no gradient
+    let c = Var::constant(5.0);
+    let w = -(c * x);
+    let gradients = w.backward();
+    assert_eq!(gradients.wrt(&x), -5.0);
+    assert_eq!(gradients.wrt(&c), 0.0);
+}
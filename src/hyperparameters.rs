@@ -3,7 +3,13 @@
 //! 
 //! Hyperparameters abstraction.
 
-use crate::Numeric;
+use crate::{
+    Numeric,
+    optim::{
+        Algorithm,
+        LrSchedule,
+    },
+};
 
 #[derive(Clone, Copy, Debug)]
 /// Hyperparameters for model training.
@@ -11,7 +17,35 @@ use crate::Numeric;
 /// As of this writing, available hyperparameters are:
 /// - `epochs: usize`: training epochs
 /// - `lr: T`: learning rate
+/// - `optimizer: Algorithm<T>`: optimization algorithm
+/// - `schedule: LrSchedule<T>`: learning rate schedule
+/// 
+/// Set only the hyperparameters of interest with `..Default::default()`, e.g.
+/// `Hyperparameters { epochs: 200, optimizer: Algorithm::Adagrad {epsilon: 1e-8}, ..Default::default() }`.
 pub struct Hyperparameters<T: Numeric> {
     pub epochs: usize,
     pub lr: T,
+    pub optimizer: Algorithm<T>,
+    pub schedule: LrSchedule<T>,
+}
+
+impl<T: Numeric> Hyperparameters<T> {
+    /// Construct new hyperparameters for training with batch gradient descent
+    /// and a constant learning rate.
+    pub fn new(epochs: usize, lr: T) -> Self {
+        Self {
+            epochs,
+            lr,
+            optimizer: Algorithm::GradientDescent,
+            schedule: LrSchedule::Constant,
+        }
+    }
+}
+
+impl<T: Numeric> Default for Hyperparameters<T> {
+    /// Construct new hyperparameters for training for 100 epochs with batch
+    /// gradient descent and a constant learning rate of `0.01`.
+    fn default() -> Self {
+        Self::new(100, T::from_f64(0.01))
+    }
 }
@@ -0,0 +1,245 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Multi-head self-attention network layer.
+
+use crate::{
+    activation::stable_softmax,
+    layer::{
+        Layer,
+        Linear,
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::{
+        Batch,
+        Tensor,
+    },
+};
+
+/// Multi-head self-attention network layer.
+/// 
+/// An attention layer `MultiHeadAttention<B, T, L, D, NH, N, BL>` maps sequences of `L` tokens of
+/// size `D`, flattened one token after another into `Tensor`s of size `N = L*D`, to sequences of
+/// the same shape.  Each token is projected to a query, key and value by `Linear` layers, which
+/// are split into `NH` heads of size `D/NH`.  Each head attends with scaled dot-product attention
+/// `softmax(q k^T / sqrt(D/NH)) v`, and the concatenated heads are projected by a final `Linear`
+/// layer.  The projections see every token of the batch as a separate input, so that `BL = B*L`.
+/// 
+/// If `causal` is set, each token attends only to itself and earlier tokens.
+pub struct MultiHeadAttention<const B: usize, T: Numeric, const L: usize, const D: usize, const NH: usize, const N: usize, const BL: usize> {
+    /// Query projection.
+    query: Linear<BL, T, D, D>,
+
+    /// Key projection.
+    key: Linear<BL, T, D, D>,
+
+    /// Value projection.
+    value: Linear<BL, T, D, D>,
+
+    /// Output projection.
+    output: Linear<BL, T, D, D>,
+
+    /// Queries, keys and values of the last forward pass.
+    qkv: [Batch<BL, T, D>; 3],
+
+    /// Attention weights of the last forward pass, indexed by batch, head, query and key.
+    weights: Vec<T>,
+
+    /// Should tokens attend only to earlier tokens?
+    pub causal: bool,
+}
+
+impl<const B: usize, T: Numeric, const L: usize, const D: usize, const NH: usize, const N: usize, const BL: usize> MultiHeadAttention<B, T, L, D, NH, N, BL> {
+    /// Number of keys attended to by query `i`.
+    fn keys(&self, i: usize) -> usize {
+        if self.causal {
+            i + 1
+        } else {
+            L
+        }
+    }
+
+    /// Index of the attention weight of query `i` for key `j` in head `h` of tensor `b`.
+    fn weight(b: usize, h: usize, i: usize, j: usize) -> usize {
+        ((b*NH+h)*L+i)*L+j
+    }
+}
+
+impl<const B: usize, T: Numeric, const L: usize, const D: usize, const NH: usize, const N: usize, const BL: usize> Layer<B, T, N, N> for MultiHeadAttention<B, T, L, D, NH, N, BL> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(N == L * D, "size N must be L*D");
+            assert!(BL == B * L, "projection batch size BL must be B*L");
+            assert!(NH > 0 && D.is_multiple_of(NH), "token size D must be a multiple of the number of heads NH");
+        };
+
+        Self {
+            query: Linear::new(),
+            key: Linear::new(),
+            value: Linear::new(),
+            output: Linear::new(),
+            qkv: [Batch::zero(), Batch::zero(), Batch::zero()],
+            weights: vec![T::zero(); B*NH*L*L],
+            causal: false,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let tokens = to_tokens::<B, T, L, D, N, BL>(batch);
+        self.qkv = [
+            self.query.forward(&tokens),
+            self.key.forward(&tokens),
+            self.value.forward(&tokens),
+        ];
+        let [q, k, v] = &self.qkv;
+
+        let s = D / NH;
+        let scale = T::one() / T::from_f64(s as f64).sqrt();
+
+        let mut context = Batch::<BL, T, D>::zero();
+        let mut scores = [T::zero(); L];
+        for b in 0..B {
+            for h in 0..NH {
+                for i in 0..L {
+                    let query = &q[b*L+i].as_array()[h*s..(h+1)*s];
+
+                    // Scaled dot products with the keys in view
+                    let keys = self.keys(i);
+                    for j in 0..keys {
+                        let key = &k[b*L+j].as_array()[h*s..(h+1)*s];
+                        scores[j] = T::zero();
+                        for e in 0..s {
+                            scores[j] = scores[j] + query[e] * key[e];
+                        }
+                        scores[j] = scores[j] * scale;
+                    }
+
+                    let w = Self::weight(b, h, i, 0);
+                    stable_softmax(&scores[..keys], &mut self.weights[w..w+keys]);
+
+                    // Weighted sum of the values in view
+                    let output = &mut context[b*L+i].as_mut_array()[h*s..(h+1)*s];
+                    for j in 0..keys {
+                        let value = &v[b*L+j].as_array()[h*s..(h+1)*s];
+                        for e in 0..s {
+                            output[e] = output[e] + self.weights[w+j] * value[e];
+                        }
+                    }
+                }
+            }
+        }
+
+        from_tokens::<B, T, L, D, N, BL>(&self.output.forward(&context))
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let dcontext = self.output.backward(&to_tokens::<B, T, L, D, N, BL>(gradients));
+        let [q, k, v] = &self.qkv;
+
+        let s = D / NH;
+        let scale = T::one() / T::from_f64(s as f64).sqrt();
+
+        let mut dq = Batch::<BL, T, D>::zero();
+        let mut dk = Batch::<BL, T, D>::zero();
+        let mut dv = Batch::<BL, T, D>::zero();
+        let mut dweights = [T::zero(); L];
+        for b in 0..B {
+            for h in 0..NH {
+                for i in 0..L {
+                    let keys = self.keys(i);
+                    let w = Self::weight(b, h, i, 0);
+                    let dout = &dcontext[b*L+i].as_array()[h*s..(h+1)*s];
+
+                    // Gradients of attention weights and values
+                    for j in 0..keys {
+                        let value = &v[b*L+j].as_array()[h*s..(h+1)*s];
+                        let dvalue = &mut dv[b*L+j].as_mut_array()[h*s..(h+1)*s];
+                        dweights[j] = T::zero();
+                        for e in 0..s {
+                            dweights[j] = dweights[j] + dout[e] * value[e];
+                            dvalue[e] = dvalue[e] + self.weights[w+j] * dout[e];
+                        }
+                    }
+
+                    // Gradients of scores through the softmax
+                    let mut dot = T::zero();
+                    for (weight, dweight) in self.weights[w..w+keys].iter().zip(dweights.iter()) {
+                        dot = dot + *weight * *dweight;
+                    }
+
+                    // Gradients of queries and keys
+                    let query = &q[b*L+i].as_array()[h*s..(h+1)*s];
+                    for j in 0..keys {
+                        let dscore = self.weights[w+j] * (dweights[j] - dot) * scale;
+                        let key = &k[b*L+j].as_array()[h*s..(h+1)*s];
+                        let dquery = &mut dq[b*L+i].as_mut_array()[h*s..(h+1)*s];
+                        for e in 0..s {
+                            dquery[e] = dquery[e] + dscore * key[e];
+                        }
+                        let dkey = &mut dk[b*L+j].as_mut_array()[h*s..(h+1)*s];
+                        for e in 0..s {
+                            dkey[e] = dkey[e] + dscore * query[e];
+                        }
+                    }
+                }
+            }
+        }
+
+        let dtokens = &(&self.query.backward(&dq) + &self.key.backward(&dk)) + &self.value.backward(&dv);
+
+        // NOTE the projections average parameter gradients over all `B*L` tokens, while
+        // layers average over the `B` tensors of the batch
+        let l_as_t = T::from_f64(L as f64);
+        for parameter in self.parameters() {
+            for gradient in parameter.gradient.iter_mut() {
+                *gradient = *gradient * l_as_t;
+            }
+        }
+
+        from_tokens::<B, T, L, D, N, BL>(&dtokens)
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        let mut parameters = self.query.parameters();
+        parameters.extend(self.key.parameters());
+        parameters.extend(self.value.parameters());
+        parameters.extend(self.output.parameters());
+        parameters
+    }
+}
+
+/// Split a batch of sequences into a batch of their tokens.
+fn to_tokens<const B: usize, T: Numeric, const L: usize, const D: usize, const N: usize, const BL: usize>(batch: &Batch<B, T, N>) -> Batch<BL, T, D> {
+    Batch (std::array::from_fn(|r| {
+        let sequence = batch[r / L].as_array();
+        Tensor::from_fn(|e| sequence[(r % L)*D+e])
+    }))
+}
+
+/// Join a batch of tokens into a batch of sequences.
+fn from_tokens<const B: usize, T: Numeric, const L: usize, const D: usize, const N: usize, const BL: usize>(tokens: &Batch<BL, T, D>) -> Batch<B, T, N> {
+    Batch (std::array::from_fn(|b| Tensor::from_fn(|i| tokens[b*L + i/D][i % D])))
+}
+
+#[test]
+fn test_attention_causal() {
+    let mut attention = MultiHeadAttention::<1, f64, 3, 4, 2, 12, 3>::new();
+    attention.causal = true;
+
+    let batch = Batch::<1, f64, 12> ([Tensor::from_fn(|i| i as f64 / 12.0)]);
+    let result = attention.forward(&batch);
+
+    // Changing the last token does not affect earlier outputs
+    let mut changed = batch.clone();
+    changed[0][10] = 5.0;
+    let changed = attention.forward(&changed);
+    assert_eq!(result[0].as_array()[..8], changed[0].as_array()[..8]);
+    assert_ne!(result[0].as_array()[8..], changed[0].as_array()[8..]);
+
+    // The first token attends only to itself
+    for h in 0..2 {
+        assert_eq!(attention.weights[MultiHeadAttention::<1, f64, 3, 4, 2, 12, 3>::weight(0, h, 0, 0)], 1.0);
+    }
+}
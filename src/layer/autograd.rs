@@ -0,0 +1,147 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Automatically differentiated network layer.
+
+use std::marker::PhantomData;
+
+use crate::{
+    differentiation::{
+        Function,
+        Graph,
+        Var,
+    },
+    layer::Layer,
+    Numeric,
+    optim::Parameter,
+    tensor::{
+        Batch,
+        Tensor,
+    },
+};
+
+/// Automatically differentiated network layer.
+/// 
+/// An `Autograd<B, T, N, M, F>` layer maps `Tensor`s of size `N` and type `T` to `Tensor`s
+/// of size `M` and identical type using the `Function` `F`.  Only the forward pass of `F`
+/// need be written: the backward pass replays it over `Var`s on a tape.
+pub struct Autograd<const B: usize, T: Numeric, const N: usize, const M: usize, F: Function<N, M>> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer parameters.
+    pub parameters: Vec<T>,
+
+    /// Gradients of layer parameters.
+    gradient: Vec<T>,
+
+    /// Function computed by this layer.
+    function: PhantomData<F>,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const M: usize, F: Function<N, M>> Layer<B, T, N, M> for Autograd<B, T, N, M, F> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        Self {
+            input: Batch::zero(),
+            parameters: (0..F::PARAMETERS).map(|_| T::random()).collect(),
+            gradient: vec![T::zero(); F::PARAMETERS],
+            function: PhantomData,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, M>::zero();
+        for b in 0..B {
+            result[b] = F::forward(&self.parameters, &batch[b]);
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+        let mut b_as_t = T::zero();
+        for _ in 0..B {
+            b_as_t = b_as_t + T::one();
+        }
+
+        self.gradient = vec![T::zero(); F::PARAMETERS];
+        for b in 0..B {
+            // Replay the forward pass on a tape
+            let graph = Graph::new();
+            let parameters: Vec<Var<'_, T>> = self.parameters.iter().map(|p| graph.var(*p)).collect();
+            let input = Tensor::<Var<'_, T>, N>::from_fn(|i| graph.var(self.input[b][i]));
+            let output = F::forward(&parameters, &input);
+
+            // Chain output gradients through the tape
+            let mut total = Var::zero();
+            for i in 0..M {
+                total = total + Var::constant(gradients[b][i]) * output[i];
+            }
+            let tape = total.backward();
+
+            for i in 0..N {
+                result[b][i] = tape.wrt(&input[i]);
+            }
+            for (p, parameter) in parameters.iter().enumerate() {
+                self.gradient[p] = self.gradient[p] + tape.wrt(parameter) / b_as_t;
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(&mut self.parameters, &mut self.gradient),
+        ]
+    }
+}
+
+#[cfg(test)]
+/// Scaled product `y = w0 * x0 * x1 + exp(w1 * x0)`.
+struct ScaledProduct;
+
+#[cfg(test)]
+impl Function<2, 1> for ScaledProduct {
+    const PARAMETERS: usize = 2;
+
+    fn forward<V: Numeric>(parameters: &[V], input: &Tensor<V, 2>) -> Tensor<V, 1> {
+        Tensor::new([parameters[0] * input[0] * input[1] + (parameters[1] * input[0]).exp()])
+    }
+}
+
+#[test]
+fn test_autograd_layer() {
+    let mut layer = Autograd::<2, f64, 2, 1, ScaledProduct>::new();
+    layer.parameters = vec![2.0, 0.5];
+
+    let batch = Batch::<2, f64, 2> ([
+        Tensor::<f64, 2>::new([1.0, 3.0]),
+        Tensor::<f64, 2>::new([2.0, -1.0]),
+    ]);
+    let result = layer.forward(&batch);
+    assert_eq!(result[0][0], 6.0 + 0.5f64.exp());
+    assert_eq!(result[1][0], -4.0 + 1.0f64.exp());
+
+    let gradients = Batch::<2, f64, 1> ([
+        Tensor::<f64, 1>::new([1.0]),
+        Tensor::<f64, 1>::new([2.0]),
+    ]);
+    let input_gradients = layer.backward(&gradients);
+
+    // dy/dx0 = w0 * x1 + w1 * exp(w1 * x0), dy/dx1 = w0 * x0
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+    assert!(close(input_gradients[0][0], 6.0 + 0.5 * 0.5f64.exp()));
+    assert!(close(input_gradients[0][1], 2.0));
+    assert!(close(input_gradients[1][0], 2.0 * (-2.0 + 0.5 * 1.0f64.exp())));
+    assert!(close(input_gradients[1][1], 2.0 * 4.0));
+
+    // dy/dw0 = x0 * x1, dy/dw1 = x0 * exp(w1 * x0), averaged over the batch
+    let parameters = layer.parameters();
+    assert!(close(parameters[0].gradient[0], (3.0 + 2.0 * -2.0) / 2.0));
+    assert!(close(parameters[0].gradient[1], (0.5f64.exp() + 2.0 * 2.0 * 1.0f64.exp()) / 2.0));
+}
@@ -4,8 +4,15 @@
 //! Average pooling network layer.
 
 use crate::{
-    layer::Layer,
+    layer::{
+        Layer,
+        pooling::{
+            check_shape,
+            source,
+        },
+    },
     Numeric,
+    optim::Parameter,
     tensor::Batch,
 };
 
@@ -14,43 +21,80 @@ use crate::{
 /// An average pooling layer `AvgPool<B, T, W, H, N, X, Y, M, K>` maps `Tensor`s of size `N = W*H` and type `T`
 /// to `Tensor`s of size `M = X * Y` and identical type using a square average pool of size `K`.  `Tensor`s
 /// are evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`) and `padding` (default zero) of this layer may be set after construction.
+/// Padding reads zeros, and may be at most `K/2`.  The output shape must then satisfy
+/// `X = (W + 2*padding - K)/stride + 1` (and likewise for `Y`), which is checked on every pass.
+/// 
+/// The pooling `kernel` holds the weight of each tap, initially `1/(K*K)`.  It is a trainable
+/// parameter only if the layer is constructed with `AvgPool::learnable`, so that the parameters of a
+/// layer never change after construction.
 pub struct AvgPool<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> {
     /// Last network layer input.
     input: Batch<B, T, N>,
 
     /// Layer kernel.
     pub kernel: [[T; K]; K],
+
+    /// Gradients of layer kernel.
+    kernel_gradient: [[T; K]; K],
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Is the kernel a trainable parameter?
+    learnable: bool,
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> AvgPool<B, T, W, H, N, X, Y, M, K> {
+    /// Construct a new layer whose kernel is a trainable parameter.
+    pub fn learnable() -> Self {
+        Self {
+            learnable: true,
+            ..Layer::new()
+        }
+    }
 }
 
 impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> Layer<B, T, N, M> for AvgPool<B, T, W, H, N, X, Y, M, K> {
     /// Construct a new layer.
     fn new() -> Self {
-        let mut ksq_as_t = T::zero();
-        for _ in 0..K {
-            for _ in 0..K {
-                ksq_as_t = ksq_as_t + T::one();
-            }
-        }
+        const {
+            assert!(N == W * H, "input size N must be W*H");
+            assert!(M == X * Y, "output size M must be X*Y");
+            assert!(K > 0, "pool must not be empty");
+        };
 
         Self {
             input: Batch::<B, T, N>::zero(),
-            kernel: [[T::one() / ksq_as_t; K]; K],
+            kernel: [[T::one() / T::from_f64((K * K) as f64); K]; K],
+            kernel_gradient: [[T::zero(); K]; K],
+            stride: K,
+            padding: 0,
+            learnable: false,
         }
     }
     
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
-        self.input = *batch;
+        check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y)]);
+        self.input = batch.clone();
 
         let mut result = Batch::<B, T, M>::zero();
 
         for b in 0..B {
-            let input = batch[b];
-
-            for i in 0..X {
-                for j in 0..Y {
-                    for kx in 0..K {
-                        for ky in 0..K {
-                            result[b][j*X+i] = result[b][j*X+i] + self.kernel[ky][kx] * input[(j*K+ky)*W+(i*K+kx)];
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for y in 0..Y {
+                for x in 0..X {
+                    for ky in 0..K {
+                        for kx in 0..K {
+                            if let Some (s) = source::<W, H>(y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                output[y*X+x] = output[y*X+x] + self.kernel[ky][kx] * input[s];
+                            }
                         }
                     }
                 }
@@ -60,27 +104,31 @@ impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize,
         result
     }
 
-    fn backward(&mut self, gradients: &Batch<B, T, M>, _lr: T) -> Batch<B, T, N> {
-        let mut result = Batch::zero();
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y)]);
 
-        // Compute gradients of input values
+        let mut result = Batch::<B, T, N>::zero();
+        let b_as_t = T::from_f64(B as f64);
+
+        // Compute gradients of input values and kernel, averaging the latter over the batch
+        self.kernel_gradient = [[T::zero(); K]; K];
         for b in 0..B {
-            let gradient = gradients[b];
-
-            for i in 0..W {
-                for j in 0..H {
-                    for kx in 0..K {
-                        for ky in 0..K {
-                            // NOTE we add `K*K` here and subtract it later to prevent
-                            // integer underflow with type `usize`
-                            let gy = j + K*K - K*ky;
-                            let gx = i + K*K - K*kx;
-                            let g = if K*K <= gx && gx < X+K*K && K*K <= gy && gy < Y+K*K {
-                                gradient[(gy-K*K)*X+(gx-K*K)]
-                            } else {
-                                T::zero()
-                            };
-                            result[b][j*W+i] = result[b][j*W+i] + self.kernel[ky][kx] * g;
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for y in 0..Y {
+                for x in 0..X {
+                    let g = gradient[y*X+x];
+                    for ky in 0..K {
+                        for kx in 0..K {
+                            // Overlapping windows accumulate gradients
+                            if let Some (s) = source::<W, H>(y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                output[s] = output[s] + self.kernel[ky][kx] * g;
+                                if self.learnable {
+                                    self.kernel_gradient[ky][kx] = self.kernel_gradient[ky][kx] + input[s] * g / b_as_t;
+                                }
+                            }
                         }
                     }
                 }
@@ -89,17 +137,33 @@ impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize,
 
         result
     }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        if !self.learnable {
+            return Vec::new();
+        }
+
+        vec![
+            Parameter::new(self.kernel.as_flattened_mut(), self.kernel_gradient.as_flattened_mut()),
+        ]
+    }
 }
 
 #[cfg(test)]
-use crate::tensor::Tensor;
+use crate::{
+    optim::{
+        GradientDescent,
+        Optimizer,
+    },
+    tensor::Tensor,
+};
 
 #[test]
 fn test_avgpool_layer() {
     let mut avgpool = AvgPool::<1, f64, 4, 4, 16, 2, 2, 4, 2>::new();
 
     // Input image
-    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16> ([
+    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([
         1.0, 2.0, 3.0, 4.0,
         5.0, 6.0, 7.0, 8.0,
         5.0, 6.0, 7.0, 8.0,
@@ -108,19 +172,19 @@ fn test_avgpool_layer() {
 
     // Compute result and expected result
     let result = avgpool.forward(&image);
-    let expected = Batch::<1, f64, 4> ([Tensor::<f64, 4> ([
+    let expected = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([
         3.50, 5.50,
         3.50, 5.50,
     ])]);
     assert_eq!(result, expected);
 
     // Compute input gradients and expected result
-    let gradients = Batch::<1, f64, 4> ([Tensor::<f64, 4> ([
+    let gradients = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([
         1.0, 1.0,
         1.0, 1.0,
     ])]);
-    let input_gradients = avgpool.backward(&gradients, 1.0);
-    let expected = Batch::<1, f64, 16> ([Tensor::<f64, 16> ([
+    let input_gradients = avgpool.backward(&gradients);
+    let expected = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([
         0.25, 0.25, 0.25, 0.25,
         0.25, 0.25, 0.25, 0.25,
         0.25, 0.25, 0.25, 0.25,
@@ -128,3 +192,41 @@ fn test_avgpool_layer() {
     ])]);
     assert_eq!(expected, input_gradients);
 }
+
+#[test]
+fn test_avgpool_overlapping() {
+    // 3x3 pools with stride 2 and one pixel of zero padding
+    let mut avgpool = AvgPool::<1, f64, 4, 4, 16, 2, 2, 4, 3>::new();
+    avgpool.kernel = [[1.0; 3]; 3];
+    avgpool.stride = 2;
+    avgpool.padding = 1;
+
+    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([1.0; 16])]);
+    let result = avgpool.forward(&image);
+    assert_eq!(result[0], Tensor::new([4.0, 6.0, 6.0, 9.0]));
+
+    // Values shared by overlapping pools accumulate gradients
+    let gradients = Batch::<1, f64, 4> ([Tensor::new([1.0; 4])]);
+    let input_gradients = avgpool.backward(&gradients);
+    assert_eq!(input_gradients[0], Tensor::new([
+        1.0, 2.0, 1.0, 1.0,
+        2.0, 4.0, 2.0, 2.0,
+        1.0, 2.0, 1.0, 1.0,
+        1.0, 2.0, 1.0, 1.0,
+    ]));
+
+    // The kernel is fixed by default
+    assert!(avgpool.parameters().is_empty());
+}
+
+#[test]
+fn test_avgpool_learnable() {
+    let mut avgpool = AvgPool::<1, f64, 2, 2, 4, 1, 1, 1, 2>::learnable();
+    assert_eq!(avgpool.parameter_count(), 4);
+
+    let image = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([1.0, 2.0, 3.0, 4.0])]);
+    avgpool.forward(&image);
+    avgpool.backward(&Batch::<1, f64, 1> ([Tensor::new([1.0])]));
+    GradientDescent::new().step(&mut avgpool.parameters(), 1.0);
+    assert_eq!(avgpool.kernel, [[-0.75, -1.75], [-2.75, -3.75]]);
+}
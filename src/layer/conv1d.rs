@@ -0,0 +1,191 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Multi-channel one-dimensional convolutional network layer.
+
+use crate::{
+    layer::{
+        convnd::ConvGeometry,
+        Layer,
+        Padding,
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Multi-channel one-dimensional convolutional network layer.
+/// 
+/// A convolutional layer `Conv1d<B, T, C, W, N, F, X, M, K>` maps `Tensor`s of `C` channels
+/// of length `W`, so that `N = C*W`, to `Tensor`s of `F` channels of length `X`, so that
+/// `M = F*X`, using `F` kernels of length `K` spanning all input channels.  Channels are
+/// stored one after another, e.g. as the tracks of an audio signal.
+/// 
+/// This is a `GroupedConv1d` with a single group; see there for its options.
+pub type Conv1d<const B: usize, T, const C: usize, const W: usize, const N: usize, const F: usize, const X: usize, const M: usize, const K: usize> = GroupedConv1d<B, T, C, W, N, F, X, M, K, 1, C>;
+
+/// Grouped one-dimensional convolutional network layer.
+/// 
+/// A grouped convolutional layer `GroupedConv1d<B, T, C, W, N, F, X, M, K, G, P>` maps
+/// `Tensor`s of `C` channels of length `W`, so that `N = C*W`, to `Tensor`s of `F` channels
+/// of length `X`, so that `M = F*X`, using `F` kernels of length `K`.  Input and output
+/// channels are split into `G` consecutive groups, and each kernel spans only the `P = C/G`
+/// input channels of its own group, as in `GroupedConv2d`.
+/// 
+/// The `stride`, `padding`, `dilation` and `padding_mode` of this layer may be set after
+/// construction, and default to an unpadded convolution with unit stride and dilation.
+/// The output length must then satisfy `X = (W + 2*padding - dilation*(K-1) - 1)/stride + 1`,
+/// which is checked on every pass.
+pub struct GroupedConv1d<const B: usize, T: Numeric, const C: usize, const W: usize, const N: usize, const F: usize, const X: usize, const M: usize, const K: usize, const G: usize, const P: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernels, indexed by output channel, input channel within its group and tap.
+    pub kernel: [[[T; K]; P]; F],
+
+    /// Layer bias of each output channel.
+    pub bias: [T; F],
+
+    /// Gradients of layer kernels.
+    kernel_gradient: [[[T; K]; P]; F],
+
+    /// Gradients of layer bias.
+    bias_gradient: [T; F],
+
+    /// Step between kernel applications.
+    pub stride: usize,
+
+    /// Padding added to each end of the input.
+    pub padding: usize,
+
+    /// Step between kernel taps.
+    pub dilation: usize,
+
+    /// Values read from the padding.
+    pub padding_mode: Padding,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const N: usize, const F: usize, const X: usize, const M: usize, const K: usize, const G: usize, const P: usize> GroupedConv1d<B, T, C, W, N, F, X, M, K, G, P> {
+    /// Shape and options of this convolution.
+    fn geometry(&self) -> ConvGeometry<1> {
+        ConvGeometry {
+            input: [W],
+            output: [X],
+            kernel: K,
+            groups: G,
+            stride: self.stride,
+            padding: self.padding,
+            dilation: self.dilation,
+            padding_mode: self.padding_mode,
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const N: usize, const F: usize, const X: usize, const M: usize, const K: usize, const G: usize, const P: usize> Layer<B, T, N, M> for GroupedConv1d<B, T, C, W, N, F, X, M, K, G, P> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(N == C * W, "input size N must be C*W");
+            assert!(M == F * X, "output size M must be F*X");
+            assert!(K > 0, "kernel must not be empty");
+            assert!(G > 0 && C == G * P, "input channels C must be G*P");
+            assert!(F.is_multiple_of(G), "output channels F must be divisible by G");
+        };
+
+        // Initialize kernels and bias randomly
+        let mut kernel = [[[T::zero(); K]; P]; F];
+        let mut bias   = [T::zero(); F];
+        for (filter, bias) in kernel.iter_mut().zip(bias.iter_mut()) {
+            for channel in filter.iter_mut() {
+                for weight in channel.iter_mut() {
+                    *weight = T::random();
+                }
+            }
+            *bias = T::random();
+        }
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel,
+            bias,
+            kernel_gradient: [[[T::zero(); K]; P]; F],
+            bias_gradient: [T::zero(); F],
+            stride: 1,
+            padding: 0,
+            dilation: 1,
+            padding_mode: Padding::Zero,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.input = batch.clone();
+        self.geometry().forward(self.kernel.as_flattened().as_flattened(), &self.bias, batch)
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        self.geometry().backward(
+            self.kernel.as_flattened().as_flattened(),
+            self.kernel_gradient.as_flattened_mut().as_flattened_mut(),
+            &mut self.bias_gradient,
+            &self.input,
+            gradients,
+        )
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(
+                self.kernel.as_flattened_mut().as_flattened_mut(),
+                self.kernel_gradient.as_flattened_mut().as_flattened_mut(),
+            ),
+            Parameter::new(&mut self.bias, &mut self.bias_gradient),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_conv1d_layer() {
+    // Two tracks differenced and smoothed over pairs of samples
+    let signal = Batch::<1, f64, 8> ([Tensor::<f64, 8>::new([
+        1.0, 2.0, 4.0, 8.0,
+        1.0, 1.0, 1.0, 1.0,
+    ])]);
+
+    let mut conv = Conv1d::<1, f64, 2, 4, 8, 1, 3, 3, 2>::new();
+    conv.kernel = [[[0.5, 0.5], [-0.5, -0.5]]];
+    conv.bias = [1.0];
+    assert_eq!(conv.forward(&signal)[0], Tensor::new([1.5, 3.0, 6.0]));
+
+    // Replicated padding with stride 2 reads the end samples repeatedly
+    let mut conv = Conv1d::<1, f64, 2, 4, 8, 1, 3, 3, 3>::new();
+    conv.kernel = [[[1.0; 3], [0.0; 3]]];
+    conv.bias = [0.0];
+    conv.stride = 2;
+    conv.padding = 2;
+    conv.padding_mode = Padding::Replicate;
+    assert_eq!(conv.forward(&signal)[0], Tensor::new([3.0, 7.0, 20.0]));
+
+    let gradients = Batch::<1, f64, 3> ([Tensor::new([1.0, 1.0, 1.0])]);
+    assert_eq!(conv.backward(&gradients)[0], Tensor::new([
+        4.0, 1.0, 2.0, 2.0,
+        0.0, 0.0, 0.0, 0.0,
+    ]));
+}
+
+#[test]
+fn test_conv1d_groups() {
+    // Depthwise convolution sums pairs of samples of each track separately
+    let signal = Batch::<1, f64, 6> ([Tensor::<f64, 6>::new([
+        1.0, 2.0, 4.0,
+        1.0, 1.0, 1.0,
+    ])]);
+
+    let mut conv = GroupedConv1d::<1, f64, 2, 3, 6, 2, 2, 4, 2, 2, 1>::new();
+    conv.kernel = [[[1.0, 1.0]], [[1.0, 1.0]]];
+    conv.bias = [0.0, 0.0];
+    assert_eq!(conv.forward(&signal)[0], Tensor::new([3.0, 6.0, 2.0, 2.0]));
+    assert_eq!(conv.parameter_count(), 2*2 + 2);
+}
@@ -0,0 +1,221 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Multi-channel two-dimensional convolutional network layer.
+
+use crate::{
+    layer::{
+        convnd::ConvGeometry,
+        Layer,
+        Padding,
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Multi-channel two-dimensional convolutional network layer.
+/// 
+/// A convolutional layer `Conv2d<B, T, C, W, H, N, F, X, Y, M, K>` maps `Tensor`s of `C`
+/// channels of size `W*H`, so that `N = C*W*H`, to `Tensor`s of `F` channels of size
+/// `X*Y`, so that `M = F*X*Y`, using `F` square kernels of size `K` spanning all input
+/// channels.  Channels are stored one after another, each in row-major order.
+/// 
+/// This is a `GroupedConv2d` with a single group; see there for its options.
+pub type Conv2d<const B: usize, T, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const M: usize, const K: usize> = GroupedConv2d<B, T, C, W, H, N, F, X, Y, M, K, 1, C>;
+
+/// Grouped two-dimensional convolutional network layer.
+/// 
+/// A grouped convolutional layer `GroupedConv2d<B, T, C, W, H, N, F, X, Y, M, K, G, P>` maps
+/// `Tensor`s of `C` channels of size `W*H`, so that `N = C*W*H`, to `Tensor`s of `F` channels
+/// of size `X*Y`, so that `M = F*X*Y`, using `F` square kernels of size `K`.  Input and output
+/// channels are split into `G` consecutive groups, and each kernel spans only the `P = C/G`
+/// input channels of its own group, which reduces the number of parameters by a factor of `G`.
+/// A depthwise convolution is the special case `G = C`.  Channels are stored one after
+/// another, each in row-major order.
+/// 
+/// The `stride`, `padding`, `dilation` and `padding_mode` of this layer may be set after
+/// construction, and default to an unpadded convolution with unit stride and dilation.
+/// The output shape must then satisfy `X = (W + 2*padding - dilation*(K-1) - 1)/stride + 1`
+/// (and likewise for `Y`), which is checked on every pass.
+pub struct GroupedConv2d<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const M: usize, const K: usize, const G: usize, const P: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernels, indexed by output channel, input channel within its group, row and column.
+    pub kernel: [[[[T; K]; K]; P]; F],
+
+    /// Layer bias of each output channel.
+    pub bias: [T; F],
+
+    /// Gradients of layer kernels.
+    kernel_gradient: [[[[T; K]; K]; P]; F],
+
+    /// Gradients of layer bias.
+    bias_gradient: [T; F],
+
+    /// Step between kernel applications.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Step between kernel taps.
+    pub dilation: usize,
+
+    /// Values read from the padding.
+    pub padding_mode: Padding,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const M: usize, const K: usize, const G: usize, const P: usize> GroupedConv2d<B, T, C, W, H, N, F, X, Y, M, K, G, P> {
+    /// Shape and options of this convolution.
+    fn geometry(&self) -> ConvGeometry<2> {
+        ConvGeometry {
+            input: [W, H],
+            output: [X, Y],
+            kernel: K,
+            groups: G,
+            stride: self.stride,
+            padding: self.padding,
+            dilation: self.dilation,
+            padding_mode: self.padding_mode,
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const M: usize, const K: usize, const G: usize, const P: usize> Layer<B, T, N, M> for GroupedConv2d<B, T, C, W, H, N, F, X, Y, M, K, G, P> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(N == C * W * H, "input size N must be C*W*H");
+            assert!(M == F * X * Y, "output size M must be F*X*Y");
+            assert!(K > 0, "kernel must not be empty");
+            assert!(G > 0 && C == G * P, "input channels C must be G*P");
+            assert!(F.is_multiple_of(G), "output channels F must be divisible by G");
+        };
+
+        // Initialize kernels and bias randomly
+        let mut kernel = [[[[T::zero(); K]; K]; P]; F];
+        let mut bias   = [T::zero(); F];
+        for (filter, bias) in kernel.iter_mut().zip(bias.iter_mut()) {
+            for channel in filter.iter_mut() {
+                for row in channel.iter_mut() {
+                    for weight in row.iter_mut() {
+                        *weight = T::random();
+                    }
+                }
+            }
+            *bias = T::random();
+        }
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel,
+            bias,
+            kernel_gradient: [[[[T::zero(); K]; K]; P]; F],
+            bias_gradient: [T::zero(); F],
+            stride: 1,
+            padding: 0,
+            dilation: 1,
+            padding_mode: Padding::Zero,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.input = batch.clone();
+        self.geometry().forward(self.kernel.as_flattened().as_flattened().as_flattened(), &self.bias, batch)
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        self.geometry().backward(
+            self.kernel.as_flattened().as_flattened().as_flattened(),
+            self.kernel_gradient.as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+            &mut self.bias_gradient,
+            &self.input,
+            gradients,
+        )
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(
+                self.kernel.as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+                self.kernel_gradient.as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+            ),
+            Parameter::new(&mut self.bias, &mut self.bias_gradient),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_conv2d_padding() {
+    let image = Batch::<1, f64, 9> ([Tensor::<f64, 9>::new([
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+    ])]);
+
+    // Sum over 2x2 windows with stride 2 and one pixel of padding
+    let mut conv = Conv2d::<1, f64, 1, 3, 3, 9, 1, 2, 2, 4, 2>::new();
+    conv.kernel = [[[[1.0, 1.0], [1.0, 1.0]]]];
+    conv.bias = [0.0];
+    conv.stride = 2;
+    conv.padding = 1;
+    assert_eq!(conv.forward(&image)[0], Tensor::new([1.0, 5.0, 11.0, 28.0]));
+
+    conv.padding_mode = Padding::Replicate;
+    assert_eq!(conv.forward(&image)[0][0], 4.0);
+
+    conv.padding_mode = Padding::Reflect;
+    assert_eq!(conv.forward(&image)[0][0], 12.0);
+
+    // Every input value is read once, so input gradients are unity
+    conv.padding_mode = Padding::Zero;
+    let gradients = Batch::<1, f64, 4> ([Tensor::new([1.0; 4])]);
+    assert_eq!(conv.backward(&gradients)[0], Tensor::new([1.0; 9]));
+}
+
+#[test]
+fn test_conv2d_channels() {
+    // Two input channels mapped to two output channels with a dilated 2x2 kernel
+    let image = Batch::<1, f64, 18> ([Tensor::<f64, 18>::new([
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+
+        1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+    ])]);
+
+    let mut conv = Conv2d::<1, f64, 2, 3, 3, 18, 2, 1, 1, 2, 2>::new();
+    conv.dilation = 2;
+    conv.kernel = [
+        // Sum of corners of channel 0
+        [[[1.0, 1.0], [1.0, 1.0]], [[0.0, 0.0], [0.0, 0.0]]],
+        // Difference of channels at top-left corner
+        [[[1.0, 0.0], [0.0, 0.0]], [[-1.0, 0.0], [0.0, 0.0]]],
+    ];
+    conv.bias = [0.5, -0.5];
+    assert_eq!(conv.forward(&image)[0], Tensor::new([20.5, -0.5]));
+}
+
+#[test]
+fn test_conv2d_groups() {
+    // Two groups of two input channels, each with constant values 1, 2, 3 and 4
+    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16>::from_fn(|i| (i / 4 + 1) as f64)]);
+
+    // Each output channel sums the channels of its own group only
+    let mut conv = GroupedConv2d::<1, f64, 4, 2, 2, 16, 4, 1, 1, 4, 2, 2, 2>::new();
+    conv.kernel = [[[[1.0; 2]; 2]; 2]; 4];
+    conv.bias = [0.0; 4];
+    assert_eq!(conv.forward(&image)[0], Tensor::new([12.0, 12.0, 28.0, 28.0]));
+    assert_eq!(conv.parameter_count(), 4*2*2*2 + 4);
+
+    // Each input value is read by both output channels of its group
+    let gradients = Batch::<1, f64, 4> ([Tensor::new([1.0, 2.0, 3.0, 4.0])]);
+    assert_eq!(conv.backward(&gradients)[0], Tensor::from_fn(|i| if i < 8 { 3.0 } else { 7.0 }));
+}
@@ -0,0 +1,182 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Multi-channel three-dimensional convolutional network layer.
+
+use crate::{
+    layer::{
+        convnd::ConvGeometry,
+        Layer,
+        Padding,
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Multi-channel three-dimensional convolutional network layer.
+/// 
+/// A convolutional layer `Conv3d<B, T, C, W, H, D, N, F, X, Y, Z, M, K>` maps `Tensor`s of `C`
+/// channels of size `W*H*D`, so that `N = C*W*H*D`, to `Tensor`s of `F` channels of size
+/// `X*Y*Z`, so that `M = F*X*Y*Z`, using `F` cubic kernels of size `K` spanning all input
+/// channels.  Channels are stored one after another, each as a stack of `D` row-major slices.
+/// 
+/// This is a `GroupedConv3d` with a single group; see there for its options.
+pub type Conv3d<const B: usize, T, const C: usize, const W: usize, const H: usize, const D: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> = GroupedConv3d<B, T, C, W, H, D, N, F, X, Y, Z, M, K, 1, C>;
+
+/// Grouped three-dimensional convolutional network layer.
+/// 
+/// A grouped convolutional layer `GroupedConv3d<B, T, C, W, H, D, N, F, X, Y, Z, M, K, G, P>`
+/// maps `Tensor`s of `C` channels of size `W*H*D`, so that `N = C*W*H*D`, to `Tensor`s of `F`
+/// channels of size `X*Y*Z`, so that `M = F*X*Y*Z`, using `F` cubic kernels of size `K`.  Input
+/// and output channels are split into `G` consecutive groups, and each kernel spans only the
+/// `P = C/G` input channels of its own group, as in `GroupedConv2d`.
+/// 
+/// The `stride`, `padding`, `dilation` and `padding_mode` of this layer may be set after
+/// construction, and default to an unpadded convolution with unit stride and dilation.
+/// The output shape must then satisfy `X = (W + 2*padding - dilation*(K-1) - 1)/stride + 1`
+/// (and likewise for `Y` and `Z`), which is checked on every pass.
+pub struct GroupedConv3d<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const D: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize, const G: usize, const P: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernels, indexed by output channel, input channel within its group, slice, row and column.
+    pub kernel: [[[[[T; K]; K]; K]; P]; F],
+
+    /// Layer bias of each output channel.
+    pub bias: [T; F],
+
+    /// Gradients of layer kernels.
+    kernel_gradient: [[[[[T; K]; K]; K]; P]; F],
+
+    /// Gradients of layer bias.
+    bias_gradient: [T; F],
+
+    /// Step between kernel applications.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Step between kernel taps.
+    pub dilation: usize,
+
+    /// Values read from the padding.
+    pub padding_mode: Padding,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const D: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize, const G: usize, const P: usize> GroupedConv3d<B, T, C, W, H, D, N, F, X, Y, Z, M, K, G, P> {
+    /// Shape and options of this convolution.
+    fn geometry(&self) -> ConvGeometry<3> {
+        ConvGeometry {
+            input: [W, H, D],
+            output: [X, Y, Z],
+            kernel: K,
+            groups: G,
+            stride: self.stride,
+            padding: self.padding,
+            dilation: self.dilation,
+            padding_mode: self.padding_mode,
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const D: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize, const G: usize, const P: usize> Layer<B, T, N, M> for GroupedConv3d<B, T, C, W, H, D, N, F, X, Y, Z, M, K, G, P> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(N == C * W * H * D, "input size N must be C*W*H*D");
+            assert!(M == F * X * Y * Z, "output size M must be F*X*Y*Z");
+            assert!(K > 0, "kernel must not be empty");
+            assert!(G > 0 && C == G * P, "input channels C must be G*P");
+            assert!(F.is_multiple_of(G), "output channels F must be divisible by G");
+        };
+
+        // Initialize kernels and bias randomly
+        let mut kernel = [[[[[T::zero(); K]; K]; K]; P]; F];
+        let mut bias   = [T::zero(); F];
+        for (filter, bias) in kernel.iter_mut().zip(bias.iter_mut()) {
+            for weight in filter.as_flattened_mut().as_flattened_mut().as_flattened_mut() {
+                *weight = T::random();
+            }
+            *bias = T::random();
+        }
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel,
+            bias,
+            kernel_gradient: [[[[[T::zero(); K]; K]; K]; P]; F],
+            bias_gradient: [T::zero(); F],
+            stride: 1,
+            padding: 0,
+            dilation: 1,
+            padding_mode: Padding::Zero,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.input = batch.clone();
+        self.geometry().forward(self.kernel.as_flattened().as_flattened().as_flattened().as_flattened(), &self.bias, batch)
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        self.geometry().backward(
+            self.kernel.as_flattened().as_flattened().as_flattened().as_flattened(),
+            self.kernel_gradient.as_flattened_mut().as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+            &mut self.bias_gradient,
+            &self.input,
+            gradients,
+        )
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(
+                self.kernel.as_flattened_mut().as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+                self.kernel_gradient.as_flattened_mut().as_flattened_mut().as_flattened_mut().as_flattened_mut(),
+            ),
+            Parameter::new(&mut self.bias, &mut self.bias_gradient),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_conv3d_layer() {
+    // A 3x3x3 volume holding its own flat index
+    let volume = Batch::<1, f64, 27> ([Tensor::<f64, 27>::from_fn(|i| i as f64)]);
+
+    // Sum over 2x2x2 cubes with stride 2 and one voxel of zero padding
+    let mut conv = Conv3d::<1, f64, 1, 3, 3, 3, 27, 1, 2, 2, 2, 8, 2>::new();
+    conv.kernel = [[[[[1.0; 2]; 2]; 2]]];
+    conv.bias = [0.0];
+    conv.stride = 2;
+    conv.padding = 1;
+    assert_eq!(conv.forward(&volume)[0], Tensor::new([
+        0.0, 3.0,
+        9.0, 24.0,
+
+        27.0, 60.0,
+        72.0, 156.0,
+    ]));
+
+    // Every input value is read once, so input gradients are unity
+    let gradients = Batch::<1, f64, 8> ([Tensor::new([1.0; 8])]);
+    assert_eq!(conv.backward(&gradients)[0], Tensor::new([1.0; 27]));
+}
+
+#[test]
+fn test_conv3d_groups() {
+    // Two channels holding constant volumes of ones and twos
+    let volume = Batch::<1, f64, 16> ([Tensor::<f64, 16>::from_fn(|i| (i / 8 + 1) as f64)]);
+
+    // Depthwise convolution sums each channel separately
+    let mut conv = GroupedConv3d::<1, f64, 2, 2, 2, 2, 16, 2, 1, 1, 1, 2, 2, 2, 1>::new();
+    conv.kernel = [[[[[1.0; 2]; 2]; 2]]; 2];
+    conv.bias = [0.0; 2];
+    assert_eq!(conv.forward(&volume)[0], Tensor::new([8.0, 16.0]));
+    assert_eq!(conv.parameter_count(), 2*8 + 2);
+}
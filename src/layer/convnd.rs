@@ -0,0 +1,163 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Grouped convolution over any number of spatial axes.
+
+use crate::{
+    layer::Padding,
+    Numeric,
+    tensor::Batch,
+};
+
+/// Shape and options of a grouped convolution over `A` spatial axes, shared by
+/// `GroupedConv1d`, `GroupedConv2d` and `GroupedConv3d`.
+/// 
+/// Axes are listed from the fastest-varying (columns) to the slowest, so that a channel
+/// of input size `[W, H]` is stored in row-major order.  Kernels are flattened by output
+/// channel, input channel within its group and tap, with taps in the same order as values,
+/// which is the layout of the nested kernel arrays of those layers.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ConvGeometry<const A: usize> {
+    /// Size of an input channel along each axis.
+    pub input: [usize; A],
+
+    /// Size of an output channel along each axis.
+    pub output: [usize; A],
+
+    /// Size of each kernel along every axis.
+    pub kernel: usize,
+
+    /// Number of groups of input and output channels.
+    pub groups: usize,
+
+    /// Step between kernel applications.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Step between kernel taps.
+    pub dilation: usize,
+
+    /// Values read from the padding.
+    pub padding_mode: Padding,
+}
+
+impl<const A: usize> ConvGeometry<A> {
+    /// Check that the output shape agrees with the stride, padding and dilation.
+    fn check_shape(&self) {
+        assert!(self.stride > 0 && self.dilation > 0, "stride and dilation must be positive");
+        let extent = self.dilation * (self.kernel - 1) + 1;
+        for (n, x) in self.input.into_iter().zip(self.output) {
+            assert!(extent <= n + 2*self.padding, "kernel must fit in the padded input");
+            assert!(
+                x == (n + 2*self.padding - extent) / self.stride + 1,
+                "output size must be (n + 2*padding - dilation*(K-1) - 1)/stride + 1 along each axis of input size n",
+            );
+        }
+    }
+
+    /// Number of taps of each kernel.
+    fn taps(&self) -> usize {
+        self.kernel.pow(A as u32)
+    }
+
+    /// Index within an input channel read by tap `t` at output `o`, both flattened, or
+    /// `None` if it reads zero padding.
+    fn source(&self, o: usize, t: usize) -> Option<usize> {
+        let (mut o, mut t) = (o, t);
+        let mut source = 0;
+        let mut scale = 1;
+        for a in 0..A {
+            let s = ((o % self.output[a])*self.stride + (t % self.kernel)*self.dilation) as isize - self.padding as isize;
+            source += self.padding_mode.source(s, self.input[a])? * scale;
+            scale *= self.input[a];
+            o /= self.output[a];
+            t /= self.kernel;
+        }
+        Some (source)
+    }
+
+    /// Convolve each `Tensor` of a batch with the given flattened `kernel`, adding the
+    /// `bias` of each output channel.
+    pub fn forward<const B: usize, T: Numeric, const N: usize, const M: usize>(&self, kernel: &[T], bias: &[T], batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.check_shape();
+
+        let n: usize = self.input.iter().product();
+        let m: usize = self.output.iter().product();
+        let taps = self.taps();
+
+        // Input and output channels of each group
+        let (p, q) = (N / n / self.groups, bias.len() / self.groups);
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for f in 0..bias.len() {
+                // First input channel of the group of this output channel
+                let offset = f / q * p;
+                for o in 0..m {
+                    let mut sum = bias[f];
+                    for t in 0..taps {
+                        if let Some (s) = self.source(o, t) {
+                            for c in 0..p {
+                                sum = sum + kernel[(f*p+c)*taps+t] * input[(offset+c)*n+s];
+                            }
+                        }
+                    }
+                    output[f*m+o] = sum;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Compute the gradients of the input values of `forward` and store the gradients
+    /// of its parameters, averaged over the batch.
+    pub fn backward<const B: usize, T: Numeric, const N: usize, const M: usize>(&self, kernel: &[T], kernel_gradient: &mut [T], bias_gradient: &mut [T], input: &Batch<B, T, N>, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        self.check_shape();
+
+        let n: usize = self.input.iter().product();
+        let m: usize = self.output.iter().product();
+        let taps = self.taps();
+
+        // Input and output channels of each group
+        let (p, q) = (N / n / self.groups, bias_gradient.len() / self.groups);
+        let b_as_t = T::from_f64(B as f64);
+
+        let mut result = Batch::<B, T, N>::zero();
+
+        kernel_gradient.fill(T::zero());
+        bias_gradient.fill(T::zero());
+        for b in 0..B {
+            let input = input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for f in 0..bias_gradient.len() {
+                let offset = f / q * p;
+                for o in 0..m {
+                    let g = gradient[f*m+o];
+                    bias_gradient[f] = bias_gradient[f] + g / b_as_t;
+
+                    for t in 0..taps {
+                        // Padded taps that read the same input value accumulate
+                        if let Some (s) = self.source(o, t) {
+                            for c in 0..p {
+                                let (i, k) = ((offset+c)*n+s, (f*p+c)*taps+t);
+                                output[i] = output[i] + kernel[k] * g;
+                                kernel_gradient[k] = kernel_gradient[k] + input[i] * g / b_as_t;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
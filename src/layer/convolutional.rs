@@ -6,6 +6,7 @@
 use crate::{
     layer::Layer,
     Numeric,
+    optim::Parameter,
     tensor::Batch,
 };
 
@@ -14,6 +15,10 @@ use crate::{
 /// A convolutional layer `Convolution<B, T, W, H, N, X, Y, M, K>` maps `Tensor`s of size `N = W*H` and type `T`
 /// to `Tensor`s of size `M = X * Y` and identical type using a square kernel of size `K`.  `Tensor`s
 /// are evaluated in `Batch`es to improve performance.
+/// 
+/// A single scalar `bias` is added to every output value.
+/// 
+/// Inconsistent sizes, such as `N != W*H` or `X != W-K+1`, fail to compile when the layer is constructed.
 pub struct Convolution<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> {
     /// Last network layer input.
     input: Batch<B, T, N>,
@@ -22,48 +27,74 @@ pub struct Convolution<const B: usize, T: Numeric, const W: usize, const H: usiz
     pub kernel: [[T; K]; K],
 
     /// Layer bias.
-    pub bias: [[T; K]; K],
+    pub bias: T,
+
+    /// Gradients of layer kernel.
+    kernel_gradient: [[T; K]; K],
 
-    /// Size of the kernel, as `T`.
-    ksq_as_t: T,
+    /// Gradient of layer bias.
+    bias_gradient: T,
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> Convolution<B, T, W, H, N, X, Y, M, K> {
+    /// Set the bias from a per-tap bias array.
+    /// 
+    /// Earlier versions of this layer stored a bias for each kernel tap and added
+    /// the mean of those biases to every output value, so the equivalent scalar
+    /// bias is their mean.
+    pub fn set_tap_bias(&mut self, taps: [[T; K]; K]) {
+        let mut sum = T::zero();
+        for row in taps.iter() {
+            for &tap in row.iter() {
+                sum = sum + tap;
+            }
+        }
+        self.bias = sum / T::from_f64((K * K) as f64);
+    }
 }
 
 impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> Layer<B, T, N, M> for Convolution<B, T, W, H, N, X, Y, M, K> {
     /// Construct a new layer, setting all parameters randomly.
     fn new() -> Self {
+        const {
+            assert!(N == W * H, "input size N must be W*H");
+            assert!(M == X * Y, "output size M must be X*Y");
+            assert!(K <= W && K <= H, "kernel must fit in the input");
+            assert!(X == W - K + 1 && Y == H - K + 1, "output shape must be (W-K+1, H-K+1)");
+        };
+
         // Initialize kernel and bias randomly
         let mut kernel = [[T::zero(); K]; K];
-        let mut bias   = [[T::zero(); K]; K];
-        let mut ksq_as_t = T::zero();
-        for i in 0..K {
-            for j in 0..K {
-                kernel[i][j] = T::random();
-                bias[i][j] = T::random();
-                ksq_as_t = ksq_as_t + T::one();
+        for row in kernel.iter_mut() {
+            for weight in row.iter_mut() {
+                *weight = T::random();
             }
         }
     
         Self {
             input: Batch::<B, T, N>::zero(),
             kernel,
-            bias,
-            ksq_as_t,
+            bias: T::random(),
+            kernel_gradient: [[T::zero(); K]; K],
+            bias_gradient: T::zero(),
         }
     }
     
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
-        self.input = *batch;
+        self.input = batch.clone();
 
         let mut result = Batch::<B, T, M>::zero();
 
         for b in 0..B {
-            let input = batch[b];
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
 
             for i in 0..X {
                 for j in 0..Y {
+                    output[j*X+i] = self.bias;
                     for kx in 0..K {
                         for ky in 0..K {
-                            result[b][j*X+i] = result[b][j*X+i] + self.kernel[ky][kx] * input[(j+ky)*W+(i+kx)] + self.bias[ky][kx] * T::one() / self.ksq_as_t;
+                            output[j*X+i] = output[j*X+i] + self.kernel[ky][kx] * input[(j+ky)*W+(i+kx)];
                         }
                     }
                 }
@@ -73,13 +104,14 @@ impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize,
         result
     }
 
-    fn backward(&mut self, gradients: &Batch<B, T, M>, lr: T) -> Batch<B, T, N> {
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
         let mut result = Batch::zero();
         let mut b_as_t = T::zero();
 
         // Compute gradients of input values
         for b in 0..B {
-            let gradient = gradients[b];
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
 
             for i in 0..W {
                 for j in 0..H {
@@ -94,7 +126,7 @@ impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize,
                             } else {
                                 T::zero()
                             };
-                            result[b][j*W+i] = result[b][j*W+i] + self.kernel[ky][kx] * g;
+                            output[j*W+i] = output[j*W+i] + self.kernel[ky][kx] * g;
                         }
                     }
                 }
@@ -102,31 +134,51 @@ impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize,
             b_as_t = b_as_t + T::one();
         }
 
-        // Update parameters
+        // Compute parameter gradients, averaged over the batch
+        self.kernel_gradient = [[T::zero(); K]; K];
+        self.bias_gradient = T::zero();
         for b in 0..B {
-            let input = self.input[b];
-            let gradient = gradients[b];
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
 
             for kx in 0..K {
                 for ky in 0..K {
                     for i in 0..X {
                         for j in 0..Y {
-                            self.kernel[ky][kx] = self.kernel[ky][kx] - lr * input[(j+ky)*W+(i+kx)] * gradient[j*X+i] * T::one() / b_as_t;
-
-                            // Derivative of output gradient is unity wrt bias
-                            self.bias[ky][kx] = self.bias[ky][kx] - lr * gradient[j*X+i] * T::one() / b_as_t;
+                            self.kernel_gradient[ky][kx] = self.kernel_gradient[ky][kx] + input[(j+ky)*W+(i+kx)] * gradient[j*X+i] / b_as_t;
                         }
                     }
                 }
             }
+
+            // Derivative of each output value is unity wrt bias
+            for &gradient in gradient.iter() {
+                self.bias_gradient = self.bias_gradient + gradient / b_as_t;
+            }
         }
 
         result
     }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(self.kernel.as_flattened_mut(), self.kernel_gradient.as_flattened_mut()),
+            Parameter::new(
+                std::slice::from_mut(&mut self.bias),
+                std::slice::from_mut(&mut self.bias_gradient),
+            ),
+        ]
+    }
 }
 
 #[cfg(test)]
-use crate::tensor::Tensor;
+use crate::{
+    optim::{
+        GradientDescent,
+        Optimizer,
+    },
+    tensor::Tensor,
+};
 
 #[test]
 fn test_convolution_layer() {
@@ -136,14 +188,14 @@ fn test_convolution_layer() {
         [-2.0,  0.0,  2.0],
         [-1.0,  0.0,  1.0],
     ];
-    conv.bias = [
+    conv.set_tap_bias([
         [ 0.0,  0.0,  0.0],
         [ 0.0,  0.0,  0.0],
         [ 0.0,  0.0,  0.0],
-    ];
+    ]);
 
     // Input image
-    let image = Batch::<1, f64, 25> ([Tensor::<f64, 25> ([
+    let image = Batch::<1, f64, 25> ([Tensor::<f64, 25>::new([
         0.0, 0.0, 1.0, 0.0, 0.0,
         0.0, 0.0, 1.0, 0.0, 0.0,
         0.0, 0.0, 1.0, 0.0, 0.0,
@@ -153,7 +205,7 @@ fn test_convolution_layer() {
 
     // Compute result and expected result
     let result = conv.forward(&image);
-    let expected = Batch::<1, f64, 9> ([Tensor::<f64, 9> ([
+    let expected = Batch::<1, f64, 9> ([Tensor::<f64, 9>::new([
         4.0, 0.0, -4.0,
         4.0, 0.0, -4.0,
         4.0, 0.0, -4.0,
@@ -161,13 +213,13 @@ fn test_convolution_layer() {
     assert_eq!(result, expected);
 
     // Compute input gradients and expected result
-    let gradients = Batch::<1, f64, 9> ([Tensor::<f64, 9> ([
+    let gradients = Batch::<1, f64, 9> ([Tensor::<f64, 9>::new([
         1.0, 1.0, 1.0,
         1.0, 1.0, 1.0,
         1.0, 1.0, 1.0,
     ])]);
-    let input_gradients = conv.backward(&gradients, 1.0);
-    let expected = Batch::<1, f64, 25> ([Tensor::<f64, 25> ([
+    let input_gradients = conv.backward(&gradients);
+    let expected = Batch::<1, f64, 25> ([Tensor::<f64, 25>::new([
         -1.0, -1.0,  0.0,  1.0,  1.0,
         -3.0, -3.0,  0.0,  3.0,  3.0,
         -4.0, -4.0,  0.0,  4.0,  4.0,
@@ -176,6 +228,9 @@ fn test_convolution_layer() {
     ])]);
     assert_eq!(expected, input_gradients);
 
+    // Update parameters
+    GradientDescent::new().step(&mut conv.parameters(), 1.0);
+
     // Check kernel
     let expected_kernel = [
         [-4.0, -3.0, -2.0],
@@ -184,11 +239,20 @@ fn test_convolution_layer() {
     ];
     assert_eq!(conv.kernel, expected_kernel);
 
-    // Check bias
-    let expected_bias = [
-        [-9.0, -9.0, -9.0],
-        [-9.0, -9.0, -9.0],
-        [-9.0, -9.0, -9.0],
-    ];
-    assert_eq!(conv.bias, expected_bias);
+    // Check bias, equivalent to a tap bias of -9.0 at each tap
+    assert_eq!(conv.bias, -9.0);
+}
+
+#[test]
+fn test_convolution_tap_bias() {
+    let mut conv = Convolution::<1, f64, 3, 3, 9, 2, 2, 4, 2>::new();
+    conv.kernel = [[0.0; 2]; 2];
+    conv.set_tap_bias([
+        [1.0, 2.0],
+        [3.0, 6.0],
+    ]);
+
+    // Each output receives the mean tap bias
+    let result = conv.forward(&Batch::zero());
+    assert_eq!(result[0], Tensor::new([3.0; 4]));
 }
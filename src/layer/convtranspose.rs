@@ -0,0 +1,214 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Transposed convolutional network layer.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Transposed convolutional network layer.
+/// 
+/// A transposed convolutional layer `ConvTranspose<B, T, W, H, N, X, Y, M, K>` maps `Tensor`s of size
+/// `N = W*H` and type `T` to `Tensor`s of size `M = X*Y` and identical type using a square kernel of size
+/// `K`.  Each input value scatters the kernel, scaled by that value, into the output, so that this layer
+/// is the adjoint of `Convolution` with the same kernel.  A single scalar `bias` is added to every output
+/// value.  `Tensor`s are evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default one) and `padding` (default zero) of this layer may be set after construction.
+/// Padding is cropped from each border of the output.  The output shape must then satisfy
+/// `X = (W-1)*stride + K - 2*padding` (and likewise for `Y`), which is checked on every pass.
+pub struct ConvTranspose<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernel.
+    pub kernel: [[T; K]; K],
+
+    /// Layer bias.
+    pub bias: T,
+
+    /// Gradients of layer kernel.
+    kernel_gradient: [[T; K]; K],
+
+    /// Gradient of layer bias.
+    bias_gradient: T,
+
+    /// Step between the kernels scattered by adjacent input values.
+    pub stride: usize,
+
+    /// Padding cropped from each border of the output.
+    pub padding: usize,
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> ConvTranspose<B, T, W, H, N, X, Y, M, K> {
+    /// Check that the output shape agrees with the stride and padding.
+    fn check_shape(&self) {
+        assert!(self.stride > 0, "stride must be positive");
+        assert!(
+            X + 2*self.padding == (W-1)*self.stride + K && Y + 2*self.padding == (H-1)*self.stride + K,
+            "output shape must be ((W-1)*stride + K - 2*padding, (H-1)*stride + K - 2*padding)",
+        );
+    }
+
+    /// Output index written by tap `(ky, kx)` of input `(j, i)`, or `None` if it is cropped.
+    fn target(&self, j: usize, i: usize, ky: usize, kx: usize) -> Option<usize> {
+        let y = (j*self.stride + ky).checked_sub(self.padding)?;
+        let x = (i*self.stride + kx).checked_sub(self.padding)?;
+        if x < X && y < Y {
+            Some (y*X+x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> Layer<B, T, N, M> for ConvTranspose<B, T, W, H, N, X, Y, M, K> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(N == W * H, "input size N must be W*H");
+            assert!(M == X * Y, "output size M must be X*Y");
+            assert!(W > 0 && H > 0 && K > 0, "input and kernel must not be empty");
+        };
+
+        // Initialize kernel and bias randomly
+        let mut kernel = [[T::zero(); K]; K];
+        for row in kernel.iter_mut() {
+            for weight in row.iter_mut() {
+                *weight = T::random();
+            }
+        }
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel,
+            bias: T::random(),
+            kernel_gradient: [[T::zero(); K]; K],
+            bias_gradient: T::zero(),
+            stride: 1,
+            padding: 0,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        self.check_shape();
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for output in output.iter_mut() {
+                *output = self.bias;
+            }
+            for j in 0..H {
+                for i in 0..W {
+                    for ky in 0..K {
+                        for kx in 0..K {
+                            if let Some (o) = self.target(j, i, ky, kx) {
+                                output[o] = output[o] + self.kernel[ky][kx] * input[j*W+i];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        self.check_shape();
+
+        let mut result = Batch::<B, T, N>::zero();
+        let b_as_t = T::from_f64(B as f64);
+
+        // Compute gradients of input values and parameters, averaging the latter over the batch
+        self.kernel_gradient = [[T::zero(); K]; K];
+        self.bias_gradient = T::zero();
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for j in 0..H {
+                for i in 0..W {
+                    for ky in 0..K {
+                        for kx in 0..K {
+                            if let Some (o) = self.target(j, i, ky, kx) {
+                                output[j*W+i] = output[j*W+i] + self.kernel[ky][kx] * gradient[o];
+                                self.kernel_gradient[ky][kx] = self.kernel_gradient[ky][kx] + input[j*W+i] * gradient[o] / b_as_t;
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Derivative of each output value is unity wrt bias
+            for &gradient in gradient.iter() {
+                self.bias_gradient = self.bias_gradient + gradient / b_as_t;
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(self.kernel.as_flattened_mut(), self.kernel_gradient.as_flattened_mut()),
+            Parameter::new(
+                std::slice::from_mut(&mut self.bias),
+                std::slice::from_mut(&mut self.bias_gradient),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    layer::Convolution,
+    tensor::Tensor,
+};
+
+#[test]
+fn test_convtranspose_adjoint() {
+    let kernel = [
+        [1.0, 2.0, 0.0],
+        [0.0, -1.0, 3.0],
+        [2.0, 0.0, 1.0],
+    ];
+
+    let mut conv = Convolution::<1, f64, 5, 4, 20, 3, 2, 6, 3>::new();
+    conv.kernel = kernel;
+    conv.bias = 0.0;
+
+    let mut convt = ConvTranspose::<1, f64, 3, 2, 6, 5, 4, 20, 3>::new();
+    convt.kernel = kernel;
+    convt.bias = 0.0;
+
+    // <conv(x), y> = <x, convt(y)>
+    let x = Batch::<1, f64, 20> ([Tensor::from_fn(|i| (i % 7) as f64 - 3.0)]);
+    let y = Batch::<1, f64, 6> ([Tensor::new([1.0, -2.0, 0.5, 3.0, 0.0, 1.0])]);
+    assert_eq!(conv.forward(&x)[0].dot(&y[0]), x[0].dot(&convt.forward(&y)[0]));
+}
+
+#[test]
+fn test_convtranspose_stride() {
+    // Stride 2 scatters non-overlapping copies of the kernel
+    let mut convt = ConvTranspose::<1, f64, 2, 1, 2, 4, 2, 8, 2>::new();
+    convt.kernel = [[1.0, 2.0], [3.0, 4.0]];
+    convt.bias = 0.5;
+    convt.stride = 2;
+
+    let result = convt.forward(&Batch::<1, f64, 2> ([Tensor::new([1.0, -1.0])]));
+    assert_eq!(result[0], Tensor::new([
+        1.5, 2.5, -0.5, -1.5,
+        3.5, 4.5, -2.5, -3.5,
+    ]));
+}
@@ -0,0 +1,99 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Dropout network layer.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    random::uniform,
+    tensor::Batch,
+};
+
+/// Dropout network layer.
+/// 
+/// A dropout layer `Dropout<B, T, N, P>` zeroes each value of `Tensor`s of size `N` with
+/// probability `P` percent during training, scaling the remaining values by `100/(100 - P)`
+/// so that their expectation is unchanged.  In inference mode this layer is the identity.
+/// 
+/// Masks are drawn from the crate's random number generator; see `alan::seed`.
+pub struct Dropout<const B: usize, T: Numeric, const N: usize, const P: usize> {
+    /// Scale applied to each value in the last forward pass, zero if it was dropped.
+    mask: Batch<B, T, N>,
+
+    /// Is this layer in training mode?
+    training: bool,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const P: usize> Layer<B, T, N, N> for Dropout<B, T, N, P> {
+    /// Construct a new layer in training mode.
+    fn new() -> Self {
+        const {
+            assert!(P < 100, "dropout probability P must be less than 100 percent");
+        };
+
+        Self {
+            mask: Batch::<B, T, N>::zero(),
+            training: true,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
+        if !self.training {
+            self.mask = Batch::zero().map(|_| T::one());
+            return batch.clone();
+        }
+
+        let p = P as f64 / 100.0;
+        let scale = T::from_f64(1.0 / (1.0 - p));
+        for b in 0..B {
+            for mask in self.mask[b].iter_mut() {
+                *mask = if uniform::<f64>() < p {
+                    T::zero()
+                } else {
+                    scale
+                };
+            }
+        }
+
+        batch * &self.mask
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, N>) -> Batch<B, T, N> {
+        gradients * &self.mask
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.training = training;
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    random::seed,
+    tensor::Tensor,
+};
+
+#[test]
+fn test_dropout_layer() {
+    let mut dropout = Dropout::<2, f64, 100, 50>::new();
+    let batch = Batch::<2, f64, 100> ([Tensor::new([1.0; 100]), Tensor::new([1.0; 100])]);
+
+    // Kept values are scaled by two, and gradients follow the same mask
+    seed(7);
+    let result = dropout.forward(&batch);
+    let kept = result.iter().flatten().filter(|&&x| x == 2.0).count();
+    let dropped = result.iter().flatten().filter(|&&x| x == 0.0).count();
+    assert_eq!(kept + dropped, 200);
+    assert!(50 < kept && kept < 150);
+    assert_eq!(dropout.backward(&batch), result);
+
+    // Masks are reproducible from a seed
+    seed(7);
+    assert_eq!(dropout.forward(&batch), result);
+
+    // Inference mode is the identity
+    dropout.set_training(false);
+    assert_eq!(dropout.forward(&batch), batch);
+    assert_eq!(dropout.backward(&batch), batch);
+}
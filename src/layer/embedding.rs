@@ -0,0 +1,173 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Embedding network layer.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Embedding network layer.
+/// 
+/// An embedding layer `Embedding<B, T, V, D, L, M>` maps `Tensor`s of `L` token indices in
+/// `0..V`, stored as values of type `T`, to the concatenation of their learnable vectors of
+/// size `D`, so that `M = L*D`.  This avoids one-hot encoding inputs over the vocabulary of
+/// size `V`.  Indices may also be passed directly with `Embedding::lookup`.
+/// 
+/// NOTE values are rounded to the nearest index, so `T` must represent every index
+/// exactly (e.g. `x16` represents indices only up to 32).
+/// 
+/// Training is sparse: the backward pass costs time proportional to the number of tokens in
+/// the batch rather than to `V`, and `Layer::parameters` returns the table as a sparse
+/// `Parameter` of the rows looked up in the last forward pass.  Each optimizer step (e.g. in
+/// `Architecture::train`) therefore updates only those rows and their optimizer state, so
+/// rows which were not looked up keep their values even under optimizers with momentum.
+pub struct Embedding<const B: usize, T: Numeric, const V: usize, const D: usize, const L: usize, const M: usize> {
+    /// Indices looked up in the last forward pass.
+    indices: [[usize; L]; B],
+
+    /// Embedding vector of each index.
+    pub table: Vec<[T; D]>,
+
+    /// Gradients of embedding vectors.
+    table_gradient: Vec<[T; D]>,
+
+    /// Rows of the table with nonzero gradients, in increasing order.
+    touched: Vec<usize>,
+}
+
+impl<const B: usize, T: Numeric, const V: usize, const D: usize, const L: usize, const M: usize> Embedding<B, T, V, D, L, M> {
+    /// Look up the vectors of a batch of index sequences.
+    pub fn lookup(&mut self, indices: &[[usize; L]; B]) -> Batch<B, T, M> {
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let output = result[b].as_mut_array();
+            for t in 0..L {
+                let index = indices[b][t];
+                assert!(index < V, "index {} out of range for vocabulary of size {}", index, V);
+                output[t*D..(t+1)*D].copy_from_slice(&self.table[index]);
+            }
+        }
+        self.indices = *indices;
+
+        result
+    }
+}
+
+impl<const B: usize, T: Numeric, const V: usize, const D: usize, const L: usize, const M: usize> Layer<B, T, L, M> for Embedding<B, T, V, D, L, M> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(M == L * D, "output size M must be L*D");
+        };
+
+        let mut table = vec![[T::zero(); D]; V];
+        for row in table.iter_mut() {
+            for value in row.iter_mut() {
+                *value = T::random();
+            }
+        }
+
+        Self {
+            indices: [[0; L]; B],
+            table,
+            table_gradient: vec![[T::zero(); D]; V],
+            touched: Vec::new(),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, L>) -> Batch<B, T, M> {
+        let mut indices = [[0; L]; B];
+        for b in 0..B {
+            for t in 0..L {
+                let index = batch[b][t].to_f64().round();
+                assert!(0.0 <= index, "index {} out of range for vocabulary of size {}", index, V);
+                indices[b][t] = index as usize;
+            }
+        }
+
+        self.lookup(&indices)
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, L> {
+        let b_as_t = T::from_f64(B as f64);
+
+        // Clear only the rows touched by the last backward pass
+        for &row in &self.touched {
+            self.table_gradient[row] = [T::zero(); D];
+        }
+        self.touched.clear();
+
+        // Accumulate gradients of looked up rows, averaged over the batch
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            for t in 0..L {
+                let row = self.indices[b][t];
+                for e in 0..D {
+                    self.table_gradient[row][e] = self.table_gradient[row][e] + gradient[t*D+e] / b_as_t;
+                }
+                self.touched.push(row);
+            }
+        }
+        self.touched.sort_unstable();
+        self.touched.dedup();
+
+        // Indices are not differentiable
+        Batch::zero()
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::sparse(
+                self.table.as_flattened_mut(),
+                self.table_gradient.as_flattened_mut(),
+                &self.touched,
+                D,
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    optim::{
+        GradientDescent,
+        Optimizer,
+    },
+    tensor::Tensor,
+};
+
+#[test]
+fn test_embedding_layer() {
+    let mut embedding = Embedding::<2, f64, 5, 2, 3, 6>::new();
+    embedding.table = vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0], [4.0, 4.0]];
+
+    let batch = Batch::<2, f64, 3> ([
+        Tensor::new([1.0, 3.0, 1.0]),
+        Tensor::new([4.0, 0.0, 1.0]),
+    ]);
+    let looked_up = embedding.lookup(&[[4, 0, 1], [1, 3, 1]]);
+    let result = embedding.forward(&batch);
+    assert_eq!(result[0], Tensor::new([1.0, 1.0, 3.0, 3.0, 1.0, 1.0]));
+    assert_eq!(looked_up[1], result[0]);
+
+    // Only looked up rows have nonzero gradients, averaged over the batch
+    let gradients = Batch::<2, f64, 6> ([Tensor::new([1.0; 6]), Tensor::new([1.0; 6])]);
+    embedding.backward(&gradients);
+    let indices: Vec<usize> = embedding.parameters()[0].indices().collect();
+    assert_eq!(indices, vec![0, 1, 2, 3, 6, 7, 8, 9]);
+    assert_eq!(embedding.parameters()[0].gradient, [0.5, 0.5, 1.5, 1.5, 0.0, 0.0, 0.5, 0.5, 0.5, 0.5]);
+
+    // Only looked up rows are updated
+    GradientDescent::new().step(&mut embedding.parameters(), 1.0);
+    assert_eq!(embedding.table, vec![[-0.5, -0.5], [-0.5, -0.5], [2.0, 2.0], [2.5, 2.5], [3.5, 3.5]]);
+
+    // Later passes clear stale gradients
+    embedding.lookup(&[[2, 2, 2], [2, 2, 2]]);
+    embedding.backward(&gradients);
+    assert_eq!(embedding.parameters()[0].gradient[..4], [0.0; 4]);
+}
@@ -0,0 +1,155 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Global pooling network layers.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    tensor::Batch,
+};
+
+/// Global average pooling network layer.
+/// 
+/// A global average pooling layer `GlobalAvgPool<B, T, C, W, H, N>` maps `Tensor`s of `C` channels
+/// of size `W*H`, so that `N = C*W*H`, to `Tensor`s of size `C` holding the mean of each channel.
+pub struct GlobalAvgPool<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize> {
+    /// Size of each channel, as `T`.
+    area_as_t: T,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize> Layer<B, T, N, C> for GlobalAvgPool<B, T, C, W, H, N> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(N == C * W * H, "input size N must be C*W*H");
+            assert!(W * H > 0, "channels must not be empty");
+        };
+
+        Self {
+            area_as_t: T::from_f64((W * H) as f64),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, C> {
+        let mut result = Batch::<B, T, C>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for c in 0..C {
+                for i in 0..W*H {
+                    output[c] = output[c] + input[c*W*H+i];
+                }
+                output[c] = output[c] / self.area_as_t;
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, C>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        // Each input value contributes equally to the mean of its channel
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for c in 0..C {
+                for i in 0..W*H {
+                    output[c*W*H+i] = gradient[c] / self.area_as_t;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Global max pooling network layer.
+/// 
+/// A global max pooling layer `GlobalMaxPool<B, T, C, W, H, N>` maps `Tensor`s of `C` channels
+/// of size `W*H`, so that `N = C*W*H`, to `Tensor`s of size `C` holding the largest value of each
+/// channel.  The backward pass routes each gradient to the largest value of its channel.
+pub struct GlobalMaxPool<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize> {
+    /// Input index of the largest value of each channel in the last forward pass, for each tensor of the batch.
+    routes: [[usize; C]; B],
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize> Layer<B, T, N, C> for GlobalMaxPool<B, T, C, W, H, N> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(N == C * W * H, "input size N must be C*W*H");
+            assert!(W * H > 0, "channels must not be empty");
+        };
+
+        Self {
+            routes: [[0; C]; B],
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, C> {
+        let mut result = Batch::<B, T, C>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for c in 0..C {
+                let mut route = c*W*H;
+                for i in c*W*H..(c+1)*W*H {
+                    if input[i] > input[route] {
+                        route = i;
+                    }
+                }
+                self.routes[b][c] = route;
+                output[c] = input[route];
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, C>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        for b in 0..B {
+            for c in 0..C {
+                result[b][self.routes[b][c]] = gradients[b][c];
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_global_pools() {
+    let fmap = Batch::<1, f64, 8> ([Tensor::<f64, 8>::new([
+        1.0, 2.0,
+        3.0, 6.0,
+
+        -1.0, -4.0,
+        -2.0, -1.0,
+    ])]);
+    let gradients = Batch::<1, f64, 2> ([Tensor::new([4.0, 8.0])]);
+
+    let mut avgpool = GlobalAvgPool::<1, f64, 2, 2, 2, 8>::new();
+    assert_eq!(avgpool.forward(&fmap)[0], Tensor::new([3.0, -2.0]));
+    assert_eq!(avgpool.backward(&gradients)[0], Tensor::new([1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0]));
+
+    let mut maxpool = GlobalMaxPool::<1, f64, 2, 2, 2, 8>::new();
+    assert_eq!(maxpool.forward(&fmap)[0], Tensor::new([6.0, -1.0]));
+    assert_eq!(maxpool.backward(&gradients)[0], Tensor::new([0.0, 0.0, 0.0, 4.0, 8.0, 0.0, 0.0, 0.0]));
+}
@@ -6,6 +6,7 @@
 use crate::{
     layer::Layer,
     Numeric,
+    optim::Parameter,
     tensor::Batch,
 };
 
@@ -23,6 +24,12 @@ pub struct Linear<const B: usize, T: Numeric, const N: usize, const M: usize> {
 
     /// Layer bias.
     bias: [T; M],
+
+    /// Gradients of layer weights.
+    weights_gradient: [[T; N]; M],
+
+    /// Gradients of layer bias.
+    bias_gradient: [T; M],
 }
 
 impl<const B: usize, T: Numeric, const N: usize, const M: usize> Layer<B, T, N, M> for Linear<B, T, N, M> {
@@ -31,40 +38,43 @@ impl<const B: usize, T: Numeric, const N: usize, const M: usize> Layer<B, T, N,
         // Initialize parameters randomly
         let mut weights = [[T::zero(); N]; M];
         let mut bias    = [T::zero(); M];
-        for i in 0..M {
-            for j in 0..N {
-                weights[i][j] = T::random();
+        for (row, bias) in weights.iter_mut().zip(bias.iter_mut()) {
+            for weight in row.iter_mut() {
+                *weight = T::random();
             }
-            bias[i] = T::random();
+            *bias = T::random();
         }
 
         Self {
             input: Batch::<B, T, N>::zero(),
             weights,
             bias,
+            weights_gradient: [[T::zero(); N]; M],
+            bias_gradient: [T::zero(); M],
         }
     }
     
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
-        self.input = *batch;
+        self.input = batch.clone();
 
         let mut result = Batch::<B, T, M>::zero();
 
         for b in 0..B {
-            let input = batch[b];
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
 
-            for i in 0..M {
-                for j in 0..N {
-                    result[b][i] = result[b][i] + self.weights[i][j] * input[j];
+            for ((output, row), &bias) in output.iter_mut().zip(self.weights.iter()).zip(self.bias.iter()) {
+                for (&weight, &x) in row.iter().zip(input.iter()) {
+                    *output = *output + weight * x;
                 }
-                result[b][i] = result[b][i] + self.bias[i];
+                *output = *output + bias;
             }
         }
 
         result
     }
 
-    fn backward(&mut self, batch: &Batch<B, T, M>, lr: T) -> Batch<B, T, N> {
+    fn backward(&mut self, batch: &Batch<B, T, M>) -> Batch<B, T, N> {
         // Backpropagate gradients
         let mut backward = Batch::<B, T, N>::zero();
         let mut b_as_t = T::zero();
@@ -77,17 +87,28 @@ impl<const B: usize, T: Numeric, const N: usize, const M: usize> Layer<B, T, N,
             b_as_t = b_as_t + T::one();
         }
 
-        // Update network parameters using batch gradient descent
-        // TODO generalize to different types of GD (e.g. SGD, Adam)
+        // Compute parameter gradients, averaged over the batch
+        self.weights_gradient = [[T::zero(); N]; M];
+        self.bias_gradient = [T::zero(); M];
         for b in 0..B {
             for i in 0..M {
                 for j in 0..N {
-                    self.weights[i][j] = self.weights[i][j] - batch[b][i] * self.input[b][j] * lr * T::one() / b_as_t;
+                    self.weights_gradient[i][j] = self.weights_gradient[i][j] + batch[b][i] * self.input[b][j] / b_as_t;
                 }
-                self.bias[i] = self.bias[i] - batch[b][i] * lr * T::one() / b_as_t;
+                self.bias_gradient[i] = self.bias_gradient[i] + batch[b][i] / b_as_t;
             }
         }
 
         backward
     }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(
+                self.weights.as_flattened_mut(),
+                self.weights_gradient.as_flattened_mut(),
+            ),
+            Parameter::new(&mut self.bias, &mut self.bias_gradient),
+        ]
+    }
 }
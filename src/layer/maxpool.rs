@@ -0,0 +1,201 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Max and min pooling network layers.
+
+use crate::{
+    layer::{
+        Layer,
+        pooling::{
+            check_shape,
+            source,
+        },
+    },
+    Numeric,
+    tensor::Batch,
+};
+
+/// Max pooling network layer.
+/// 
+/// A max pooling layer `MaxPool<B, T, W, H, N, X, Y, M, K>` maps `Tensor`s of size `N = W*H` and type `T`
+/// to `Tensor`s of size `M = X * Y` and identical type by taking the largest value in each square window
+/// of size `K`.  `Tensor`s are evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`) and `padding` (default zero) of this layer may be set after construction.
+/// Padding never wins a window, and may be at most `K/2`.  The output shape must then satisfy
+/// `X = (W + 2*padding - K)/stride + 1` (and likewise for `Y`), which is checked on every pass.
+/// 
+/// The backward pass routes each output gradient to the input value which won its window.
+pub struct MaxPool<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> {
+    /// Input index which won each window of the last forward pass, for each tensor of the batch.
+    routes: Vec<usize>,
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Min pooling network layer.
+/// 
+/// A min pooling layer `MinPool<B, T, W, H, N, X, Y, M, K>` behaves as `MaxPool`, but takes the smallest
+/// value in each window.
+pub struct MinPool<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> {
+    /// Input index which won each window of the last forward pass, for each tensor of the batch.
+    routes: Vec<usize>,
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Implement a pooling layer which keeps the value of each window preferred by `$better`.
+macro_rules! extremum_pool {
+    ($pool:ident, $better:tt) => {
+        impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize, const K: usize> Layer<B, T, N, M> for $pool<B, T, W, H, N, X, Y, M, K> {
+            /// Construct a new layer.
+            fn new() -> Self {
+                const {
+                    assert!(N == W * H, "input size N must be W*H");
+                    assert!(M == X * Y, "output size M must be X*Y");
+                    assert!(K > 0, "pool must not be empty");
+                };
+
+                Self {
+                    routes: vec![0; B*M],
+                    stride: K,
+                    padding: 0,
+                    _marker: std::marker::PhantomData,
+                }
+            }
+
+            fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+                check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y)]);
+
+                let mut result = Batch::<B, T, M>::zero();
+
+                for b in 0..B {
+                    let input = batch[b].as_array();
+                    let output = result[b].as_mut_array();
+
+                    for y in 0..Y {
+                        for x in 0..X {
+                            let mut route = None;
+                            for ky in 0..K {
+                                for kx in 0..K {
+                                    if let Some (s) = source::<W, H>(y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                        let wins = match route {
+                                            Some (r) => input[s] $better input[r],
+                                            None => true,
+                                        };
+                                        if wins {
+                                            route = Some (s);
+                                        }
+                                    }
+                                }
+                            }
+
+                            // NOTE padding is at most `K/2`, so every window holds an input value
+                            let route = route.unwrap();
+                            self.routes[b*M+y*X+x] = route;
+                            output[y*X+x] = input[route];
+                        }
+                    }
+                }
+
+                result
+            }
+
+            fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+                let mut result = Batch::<B, T, N>::zero();
+
+                // Route each gradient to the winner of its window
+                for b in 0..B {
+                    let gradient = gradients[b].as_array();
+                    let output = result[b].as_mut_array();
+
+                    for i in 0..M {
+                        let route = self.routes[b*M+i];
+                        output[route] = output[route] + gradient[i];
+                    }
+                }
+
+                result
+            }
+        }
+    };
+}
+
+extremum_pool!(MaxPool, >);
+extremum_pool!(MinPool, <);
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_maxpool_layer() {
+    let mut maxpool = MaxPool::<1, f64, 4, 4, 16, 2, 2, 4, 2>::new();
+
+    // Input image
+    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        5.0, 9.0, 7.0, 8.0,
+        1.0, 2.0, 3.0, 0.0,
+    ])]);
+
+    // Compute result and expected result
+    let result = maxpool.forward(&image);
+    let expected = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([
+        6.0, 8.0,
+        9.0, 8.0,
+    ])]);
+    assert_eq!(result, expected);
+
+    // Gradients are routed to the largest value of each window
+    let gradients = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([
+        1.0, 2.0,
+        3.0, 4.0,
+    ])]);
+    let input_gradients = maxpool.backward(&gradients);
+    let expected = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([
+        0.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 2.0,
+        0.0, 3.0, 0.0, 4.0,
+        0.0, 0.0, 0.0, 0.0,
+    ])]);
+    assert_eq!(expected, input_gradients);
+}
+
+#[test]
+fn test_minpool_overlapping() {
+    // 3x3 windows with stride 2 and one pixel of padding
+    let mut minpool = MinPool::<1, f64, 4, 4, 16, 2, 2, 4, 3>::new();
+    minpool.stride = 2;
+    minpool.padding = 1;
+
+    let image = Batch::<1, f64, 16> ([Tensor::<f64, 16>::new([
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 0.0, 8.0,
+        5.0, 9.0, 7.0, 8.0,
+        1.0, 2.0, 3.0, 4.0,
+    ])]);
+    let result = minpool.forward(&image);
+    assert_eq!(result[0], Tensor::new([1.0, 0.0, 1.0, 0.0]));
+
+    // Windows which share a winner accumulate its gradient
+    let gradients = Batch::<1, f64, 4> ([Tensor::new([1.0, 1.0, 1.0, 1.0])]);
+    let input_gradients = minpool.backward(&gradients);
+    assert_eq!(input_gradients[0][0], 1.0);
+    assert_eq!(input_gradients[0][6], 2.0);
+    assert_eq!(input_gradients[0][12], 1.0);
+}
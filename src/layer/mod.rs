@@ -3,18 +3,86 @@
 //!
 //! Network layer abstraction.
 
+mod attention;
+mod autograd;
 mod avgpool;
+mod conv1d;
+mod conv2d;
+mod conv3d;
+mod convnd;
 mod convolutional;
+mod convtranspose;
+mod dropout;
+mod embedding;
+mod globalpool;
 mod linear;
+mod maxpool;
+mod normalization;
+mod padding;
+mod pool1d;
+mod pool3d;
+mod pooling;
+mod recurrent;
+mod separable;
+mod upsample;
 
 use crate::{
     Numeric,
+    optim::Parameter,
     tensor::Batch,
 };
 
+pub use attention::MultiHeadAttention;
+pub use autograd::Autograd;
 pub use avgpool::AvgPool;
+pub use conv1d::{
+    Conv1d,
+    GroupedConv1d,
+};
+pub use conv2d::{
+    Conv2d,
+    GroupedConv2d,
+};
+pub use conv3d::{
+    Conv3d,
+    GroupedConv3d,
+};
 pub use convolutional::Convolution;
+pub use convtranspose::ConvTranspose;
+pub use dropout::Dropout;
+pub use embedding::Embedding;
+pub use globalpool::{
+    GlobalAvgPool,
+    GlobalMaxPool,
+};
 pub use linear::Linear;
+pub use maxpool::{
+    MaxPool,
+    MinPool,
+};
+pub use normalization::{
+    BatchNorm,
+    LayerNorm,
+};
+pub use padding::Padding;
+pub use pool1d::{
+    AvgPool1d,
+    MaxPool1d,
+};
+pub use pool3d::{
+    AvgPool3d,
+    MaxPool3d,
+};
+pub use recurrent::{
+    Gru,
+    Lstm,
+    Rnn,
+};
+pub use separable::DepthwiseSeparable;
+pub use upsample::{
+    Interpolation,
+    Upsample,
+};
 
 /// Network layer abstraction.
 pub trait Layer<const B: usize, T: Numeric, const N: usize, const M: usize> {
@@ -25,5 +93,35 @@ pub trait Layer<const B: usize, T: Numeric, const N: usize, const M: usize> {
     fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M>;
 
     /// Complete a backward pass through this layer.
-    fn backward(&mut self, batch: &Batch<B, T, M>, lr: T) -> Batch<B, T, N>;
+    /// 
+    /// This computes and stores the gradients of all parameters of this layer,
+    /// averaged over the batch, and returns the gradients of the layer input.
+    /// Parameters are not updated; see `alan::optim::Optimizer`.
+    fn backward(&mut self, batch: &Batch<B, T, M>) -> Batch<B, T, N>;
+
+    /// Get the trainable parameters of this layer, along with their gradients
+    /// from the last backward pass.
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        Vec::new()
+    }
+
+    /// Get the number of trainable parameter values of this layer.
+    /// 
+    /// NOTE this takes `&mut self` only because it counts the values returned by
+    /// `parameters`, which lends them out mutably; it does not modify this layer.
+    fn parameter_count(&mut self) -> usize {
+        self.parameters().iter().map(|parameter| parameter.value.len()).sum()
+    }
+
+    /// Get the non-trainable state of this layer which affects its output, such as
+    /// running statistics.
+    fn buffers(&mut self) -> Vec<&mut [T]> {
+        Vec::new()
+    }
+
+    /// Set whether this layer is in training mode (the default) or inference mode.
+    /// 
+    /// Only layers which behave differently during training (e.g. `BatchNorm`
+    /// and `Dropout`) need to implement this.
+    fn set_training(&mut self, _training: bool) {}
 }
@@ -0,0 +1,345 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Normalization network layers.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Batch normalization network layer.
+/// 
+/// A batch normalization layer `BatchNorm<B, T, C, N>` normalizes `Tensor`s of `C` channels
+/// of `N/C` values each, such as the feature maps of a `Conv2d`, so that each channel has zero
+/// mean and unit variance over the batch and the values of the channel.  Each channel is
+/// then scaled by a learnable `gamma` and shifted by a learnable `beta`.  Use `C = N` to
+/// normalize each value independently.
+/// 
+/// In training mode (the default), batch statistics are used and the running statistics
+/// are updated with the given `momentum`.  In inference mode, the running statistics are used.
+pub struct BatchNorm<const B: usize, T: Numeric, const C: usize, const N: usize> {
+    /// Normalized values of the last network layer input.
+    normalized: Batch<B, T, N>,
+
+    /// Reciprocal standard deviation of each channel in the last forward pass.
+    inv_std: [T; C],
+
+    /// Layer scale of each channel.
+    pub gamma: [T; C],
+
+    /// Layer shift of each channel.
+    pub beta: [T; C],
+
+    /// Gradients of layer scale.
+    gamma_gradient: [T; C],
+
+    /// Gradients of layer shift.
+    beta_gradient: [T; C],
+
+    /// Running mean of each channel.
+    pub running_mean: [T; C],
+
+    /// Running (unbiased) variance of each channel.
+    pub running_var: [T; C],
+
+    /// Weight of each new batch in the running statistics.
+    pub momentum: T,
+
+    /// Constant added to variances for numerical stability.
+    pub epsilon: T,
+
+    /// Is this layer in training mode?
+    training: bool,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const N: usize> Layer<B, T, N, N> for BatchNorm<B, T, C, N> {
+    /// Construct a new layer, with unit scale and zero shift.
+    fn new() -> Self {
+        const {
+            assert!(C > 0 && N.is_multiple_of(C), "size N must be a multiple of the number of channels C");
+        };
+
+        Self {
+            normalized: Batch::<B, T, N>::zero(),
+            inv_std: [T::one(); C],
+            gamma: [T::one(); C],
+            beta: [T::zero(); C],
+            gamma_gradient: [T::zero(); C],
+            beta_gradient: [T::zero(); C],
+            running_mean: [T::zero(); C],
+            running_var: [T::one(); C],
+            momentum: T::from_f64(0.1),
+            epsilon: T::from_f64(1e-5),
+            training: true,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let s = N / C;
+        let count_as_t = T::from_f64((B * s) as f64);
+
+        let mut mean = self.running_mean;
+        let mut var = self.running_var;
+
+        if self.training {
+            // Compute batch statistics of each channel
+            for c in 0..C {
+                mean[c] = T::zero();
+                var[c] = T::zero();
+                for b in 0..B {
+                    for i in c*s..(c+1)*s {
+                        mean[c] = mean[c] + batch[b][i] / count_as_t;
+                    }
+                }
+                for b in 0..B {
+                    for i in c*s..(c+1)*s {
+                        var[c] = var[c] + (batch[b][i] - mean[c]) * (batch[b][i] - mean[c]) / count_as_t;
+                    }
+                }
+
+                // NOTE running variance is unbiased, unlike the variance used to normalize
+                let unbiased = if B * s > 1 {
+                    var[c] * count_as_t / (count_as_t - T::one())
+                } else {
+                    var[c]
+                };
+                self.running_mean[c] = (T::one() - self.momentum) * self.running_mean[c] + self.momentum * mean[c];
+                self.running_var[c] = (T::one() - self.momentum) * self.running_var[c] + self.momentum * unbiased;
+            }
+        }
+
+        let mut result = Batch::<B, T, N>::zero();
+        for (inv_std, &var) in self.inv_std.iter_mut().zip(var.iter()) {
+            *inv_std = T::one() / (var + self.epsilon).sqrt();
+        }
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let normalized = self.normalized[b].as_mut_array();
+            let output = result[b].as_mut_array();
+
+            for i in 0..N {
+                let c = i / s;
+                normalized[i] = (input[i] - mean[c]) * self.inv_std[c];
+                output[i] = self.gamma[c] * normalized[i] + self.beta[c];
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let s = N / C;
+        let b_as_t = T::from_f64(B as f64);
+        let count_as_t = T::from_f64((B * s) as f64);
+
+        // Compute parameter gradients, averaged over the batch, along with the sums
+        // needed to differentiate through batch statistics
+        let mut sum = [T::zero(); C];
+        let mut dot = [T::zero(); C];
+        for c in 0..C {
+            for b in 0..B {
+                for i in c*s..(c+1)*s {
+                    sum[c] = sum[c] + gradients[b][i];
+                    dot[c] = dot[c] + gradients[b][i] * self.normalized[b][i];
+                }
+            }
+            self.gamma_gradient[c] = dot[c] / b_as_t;
+            self.beta_gradient[c] = sum[c] / b_as_t;
+        }
+
+        let mut result = Batch::<B, T, N>::zero();
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let normalized = self.normalized[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for i in 0..N {
+                let c = i / s;
+                output[i] = if self.training {
+                    // Batch statistics depend on every input value of the channel
+                    self.gamma[c] * self.inv_std[c] * (gradient[i] - sum[c] / count_as_t - normalized[i] * dot[c] / count_as_t)
+                } else {
+                    self.gamma[c] * self.inv_std[c] * gradient[i]
+                };
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(&mut self.gamma, &mut self.gamma_gradient),
+            Parameter::new(&mut self.beta, &mut self.beta_gradient),
+        ]
+    }
+
+    fn buffers(&mut self) -> Vec<&mut [T]> {
+        vec![&mut self.running_mean, &mut self.running_var]
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.training = training;
+    }
+}
+
+/// Layer normalization network layer.
+/// 
+/// A layer normalization layer `LayerNorm<B, T, N>` normalizes each `Tensor` of size `N` to zero
+/// mean and unit variance over its values, independently of the rest of the batch.  Each value
+/// is then scaled by a learnable `gamma` and shifted by a learnable `beta`.  This layer behaves
+/// identically in training and inference mode.
+pub struct LayerNorm<const B: usize, T: Numeric, const N: usize> {
+    /// Normalized values of the last network layer input.
+    normalized: Batch<B, T, N>,
+
+    /// Reciprocal standard deviation of each tensor in the last forward pass.
+    inv_std: [T; B],
+
+    /// Layer scale.
+    pub gamma: [T; N],
+
+    /// Layer shift.
+    pub beta: [T; N],
+
+    /// Gradients of layer scale.
+    gamma_gradient: [T; N],
+
+    /// Gradients of layer shift.
+    beta_gradient: [T; N],
+
+    /// Constant added to variances for numerical stability.
+    pub epsilon: T,
+}
+
+impl<const B: usize, T: Numeric, const N: usize> Layer<B, T, N, N> for LayerNorm<B, T, N> {
+    /// Construct a new layer, with unit scale and zero shift.
+    fn new() -> Self {
+        const {
+            assert!(N > 0, "size N must be positive");
+        };
+
+        Self {
+            normalized: Batch::<B, T, N>::zero(),
+            inv_std: [T::one(); B],
+            gamma: [T::one(); N],
+            beta: [T::zero(); N],
+            gamma_gradient: [T::zero(); N],
+            beta_gradient: [T::zero(); N],
+            epsilon: T::from_f64(1e-5),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        for b in 0..B {
+            let mean = batch[b].mean();
+            let var = batch[b].map(|x| (x - mean) * (x - mean)).mean();
+            self.inv_std[b] = T::one() / (var + self.epsilon).sqrt();
+
+            let input = batch[b].as_array();
+            let normalized = self.normalized[b].as_mut_array();
+            let output = result[b].as_mut_array();
+
+            for i in 0..N {
+                normalized[i] = (input[i] - mean) * self.inv_std[b];
+                output[i] = self.gamma[i] * normalized[i] + self.beta[i];
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, N>) -> Batch<B, T, N> {
+        let b_as_t = T::from_f64(B as f64);
+        let n_as_t = T::from_f64(N as f64);
+
+        let mut result = Batch::<B, T, N>::zero();
+        self.gamma_gradient = [T::zero(); N];
+        self.beta_gradient = [T::zero(); N];
+
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let normalized = self.normalized[b].as_array();
+            let output = result[b].as_mut_array();
+
+            // Gradients wrt normalized values, and the sums needed to differentiate
+            // through the statistics of this tensor
+            let mut sum = T::zero();
+            let mut dot = T::zero();
+            for i in 0..N {
+                let g = self.gamma[i] * gradient[i];
+                sum = sum + g;
+                dot = dot + g * normalized[i];
+
+                // Parameter gradients are averaged over the batch
+                self.gamma_gradient[i] = self.gamma_gradient[i] + gradient[i] * normalized[i] / b_as_t;
+                self.beta_gradient[i] = self.beta_gradient[i] + gradient[i] / b_as_t;
+            }
+
+            for i in 0..N {
+                let g = self.gamma[i] * gradient[i];
+                output[i] = self.inv_std[b] * (g - sum / n_as_t - normalized[i] * dot / n_as_t);
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(&mut self.gamma, &mut self.gamma_gradient),
+            Parameter::new(&mut self.beta, &mut self.beta_gradient),
+        ]
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_batchnorm_modes() {
+    let mut batchnorm = BatchNorm::<2, f64, 2, 4>::new();
+    batchnorm.epsilon = 0.0;
+    batchnorm.momentum = 0.5;
+
+    // Channel 0 has mean 2 and variance 2, channel 1 has mean 0 and variance 1
+    let batch = Batch::<2, f64, 4> ([
+        Tensor::new([0.0, 2.0, -1.0, 1.0]),
+        Tensor::new([2.0, 4.0, 1.0, -1.0]),
+    ]);
+    let result = batchnorm.forward(&batch);
+    let inv_root2 = 1.0 / 2.0f64.sqrt();
+    assert_eq!(result[0], Tensor::new([-2.0 * inv_root2, 0.0, -1.0, 1.0]));
+    assert_eq!(result[1], Tensor::new([0.0, 2.0 * inv_root2, 1.0, -1.0]));
+
+    // Running statistics move halfway towards the (unbiased) batch statistics
+    assert_eq!(batchnorm.running_mean, [1.0, 0.0]);
+    assert_eq!(batchnorm.running_var, [0.5 + 4.0 / 3.0, 0.5 + 2.0 / 3.0]);
+
+    // Inference mode uses running statistics and does not update them
+    batchnorm.set_training(false);
+    batchnorm.running_mean = [1.0, 0.0];
+    batchnorm.running_var = [4.0, 1.0];
+    let result = batchnorm.forward(&batch);
+    assert_eq!(result[0], Tensor::new([-0.5, 0.5, -1.0, 1.0]));
+    assert_eq!(batchnorm.running_mean, [1.0, 0.0]);
+}
+
+#[test]
+fn test_layernorm_layer() {
+    let mut layernorm = LayerNorm::<1, f64, 4>::new();
+    layernorm.epsilon = 0.0;
+    layernorm.gamma = [2.0; 4];
+    layernorm.beta = [1.0; 4];
+
+    // Mean 2.5 and variance 1.25
+    let batch = Batch::<1, f64, 4> ([Tensor::new([1.0, 2.0, 3.0, 4.0])]);
+    let result = layernorm.forward(&batch);
+    let scale = 2.0 / 1.25f64.sqrt();
+    assert_eq!(result[0], Tensor::new([1.0 - 1.5 * scale, 1.0 - 0.5 * scale, 1.0 + 0.5 * scale, 1.0 + 1.5 * scale]));
+}
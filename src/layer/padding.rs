@@ -0,0 +1,52 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Padding modes for spatial network layers.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Values read from outside the borders of a padded input.
+pub enum Padding {
+    /// Pad with zeros.
+    #[default]
+    Zero,
+
+    /// Pad by reflecting the input about its border, excluding the border itself.
+    Reflect,
+
+    /// Pad by repeating the border of the input.
+    Replicate,
+}
+
+impl Padding {
+    /// Map a possibly out-of-bounds coordinate along an axis of size `n` to the input
+    /// coordinate it reads, or `None` if it reads zero.
+    pub(crate) fn source(self, i: isize, n: usize) -> Option<usize> {
+        let n = n as isize;
+        if 0 <= i && i < n {
+            return Some (i as usize);
+        }
+
+        match self {
+            Padding::Zero => None,
+            Padding::Reflect => {
+                if n == 1 {
+                    return Some (0);
+                }
+                let period = 2 * (n - 1);
+                let i = i.rem_euclid(period);
+                Some ((if i < n { i } else { period - i }) as usize)
+            },
+            Padding::Replicate => Some (i.clamp(0, n - 1) as usize),
+        }
+    }
+}
+
+#[test]
+fn test_padding_modes() {
+    assert_eq!(Padding::Zero.source(-1, 4), None);
+    assert_eq!(Padding::Zero.source(2, 4), Some (2));
+    assert_eq!(Padding::Reflect.source(-2, 4), Some (2));
+    assert_eq!(Padding::Reflect.source(5, 4), Some (1));
+    assert_eq!(Padding::Replicate.source(-2, 4), Some (0));
+    assert_eq!(Padding::Replicate.source(5, 4), Some (3));
+}
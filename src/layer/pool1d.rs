@@ -0,0 +1,251 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! One-dimensional pooling network layers.
+
+use crate::{
+    layer::{
+        Layer,
+        pooling::{
+            check_shape,
+            unpad,
+        },
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// One-dimensional average pooling network layer.
+/// 
+/// An average pooling layer `AvgPool1d<B, T, N, M, K>` maps sequences of length `N` and type `T` to
+/// sequences of length `M` and identical type using an average pool of length `K`.  `Tensor`s are
+/// evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`), `padding` (default zero) and `kernel` of this layer behave as for
+/// `AvgPool`, and the output length must satisfy `M = (N + 2*padding - K)/stride + 1`.  The kernel
+/// is a trainable parameter only if the layer is constructed with `AvgPool1d::learnable`.
+pub struct AvgPool1d<const B: usize, T: Numeric, const N: usize, const M: usize, const K: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernel.
+    pub kernel: [T; K],
+
+    /// Gradients of layer kernel.
+    kernel_gradient: [T; K],
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each end of the input.
+    pub padding: usize,
+
+    /// Is the kernel a trainable parameter?
+    learnable: bool,
+}
+
+/// One-dimensional max pooling network layer.
+/// 
+/// A max pooling layer `MaxPool1d<B, T, N, M, K>` maps sequences of length `N` and type `T` to
+/// sequences of length `M` and identical type by taking the largest value in each window of length
+/// `K`.  `Tensor`s are evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`) and `padding` (default zero) of this layer behave as for `MaxPool`,
+/// and the output length must satisfy `M = (N + 2*padding - K)/stride + 1`.  The backward pass
+/// routes each output gradient to the input value which won its window.
+pub struct MaxPool1d<const B: usize, T: Numeric, const N: usize, const M: usize, const K: usize> {
+    /// Input index which won each window of the last forward pass, for each tensor of the batch.
+    routes: Vec<usize>,
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each end of the input.
+    pub padding: usize,
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const M: usize, const K: usize> AvgPool1d<B, T, N, M, K> {
+    /// Construct a new layer whose kernel is a trainable parameter.
+    pub fn learnable() -> Self {
+        Self {
+            learnable: true,
+            ..Layer::new()
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const M: usize, const K: usize> Layer<B, T, N, M> for AvgPool1d<B, T, N, M, K> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(K > 0, "pool must not be empty");
+        };
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel: [T::one() / T::from_f64(K as f64); K],
+            kernel_gradient: [T::zero(); K],
+            stride: K,
+            padding: 0,
+            learnable: false,
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        check_shape::<K>(self.stride, self.padding, &[(N, M)]);
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for x in 0..M {
+                for k in 0..K {
+                    if let Some (s) = unpad(x*self.stride + k, N, self.padding) {
+                        output[x] = output[x] + self.kernel[k] * input[s];
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        check_shape::<K>(self.stride, self.padding, &[(N, M)]);
+
+        let mut result = Batch::<B, T, N>::zero();
+        let b_as_t = T::from_f64(B as f64);
+
+        // Compute gradients of input values and kernel, averaging the latter over the batch
+        self.kernel_gradient = [T::zero(); K];
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for x in 0..M {
+                for k in 0..K {
+                    // Overlapping windows accumulate gradients
+                    if let Some (s) = unpad(x*self.stride + k, N, self.padding) {
+                        output[s] = output[s] + self.kernel[k] * gradient[x];
+                        if self.learnable {
+                            self.kernel_gradient[k] = self.kernel_gradient[k] + input[s] * gradient[x] / b_as_t;
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        if !self.learnable {
+            return Vec::new();
+        }
+
+        vec![
+            Parameter::new(&mut self.kernel, &mut self.kernel_gradient),
+        ]
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const M: usize, const K: usize> Layer<B, T, N, M> for MaxPool1d<B, T, N, M, K> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(K > 0, "pool must not be empty");
+        };
+
+        Self {
+            routes: vec![0; B*M],
+            stride: K,
+            padding: 0,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        check_shape::<K>(self.stride, self.padding, &[(N, M)]);
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for x in 0..M {
+                let mut route = None;
+                for k in 0..K {
+                    if let Some (s) = unpad(x*self.stride + k, N, self.padding) {
+                        let wins = match route {
+                            Some (r) => input[s] > input[r],
+                            None => true,
+                        };
+                        if wins {
+                            route = Some (s);
+                        }
+                    }
+                }
+
+                // NOTE padding is at most `K/2`, so every window holds an input value
+                let route = route.unwrap();
+                self.routes[b*M+x] = route;
+                output[x] = input[route];
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        // Route each gradient to the winner of its window
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for (&route, &gradient) in self.routes[b*M..(b+1)*M].iter().zip(gradient.iter()) {
+                output[route] = output[route] + gradient;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_pool1d_layers() {
+    let signal = Batch::<1, f64, 6> ([Tensor::<f64, 6>::new([1.0, 3.0, 2.0, 6.0, 5.0, 4.0])]);
+
+    let mut avgpool = AvgPool1d::<1, f64, 6, 3, 2>::new();
+    assert_eq!(avgpool.forward(&signal)[0], Tensor::new([2.0, 4.0, 4.5]));
+    assert!(avgpool.parameters().is_empty());
+    assert_eq!(AvgPool1d::<1, f64, 6, 3, 2>::learnable().parameter_count(), 2);
+
+    let gradients = Batch::<1, f64, 3> ([Tensor::new([1.0, 2.0, 3.0])]);
+    assert_eq!(avgpool.backward(&gradients)[0], Tensor::new([0.5, 0.5, 1.0, 1.0, 1.5, 1.5]));
+
+    // Windows of three with stride 2 and one sample of padding
+    let mut maxpool = MaxPool1d::<1, f64, 6, 3, 3>::new();
+    maxpool.stride = 2;
+    maxpool.padding = 1;
+    assert_eq!(maxpool.forward(&signal)[0], Tensor::new([3.0, 6.0, 6.0]));
+
+    // Windows which share a winner accumulate its gradient
+    assert_eq!(maxpool.backward(&gradients)[0], Tensor::new([0.0, 1.0, 0.0, 5.0, 0.0, 0.0]));
+}
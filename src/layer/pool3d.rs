@@ -0,0 +1,293 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Three-dimensional pooling network layers.
+
+use crate::{
+    layer::{
+        Layer,
+        pooling::{
+            check_shape,
+            unpad,
+        },
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Three-dimensional average pooling network layer.
+/// 
+/// An average pooling layer `AvgPool3d<B, T, W, H, D, N, X, Y, Z, M, K>` maps volumes of size
+/// `N = W*H*D` and type `T` to volumes of size `M = X*Y*Z` and identical type using a cubic average
+/// pool of size `K`.  Volumes are stored as a stack of `D` row-major slices.  `Tensor`s are
+/// evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`), `padding` (default zero) and `kernel` of this layer behave as for
+/// `AvgPool`, and the output shape must satisfy `X = (W + 2*padding - K)/stride + 1` (and likewise
+/// for `Y` and `Z`).  The kernel is a trainable parameter only if the layer is constructed with
+/// `AvgPool3d::learnable`.
+pub struct AvgPool3d<const B: usize, T: Numeric, const W: usize, const H: usize, const D: usize, const N: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, N>,
+
+    /// Layer kernel.
+    pub kernel: [[[T; K]; K]; K],
+
+    /// Gradients of layer kernel.
+    kernel_gradient: [[[T; K]; K]; K],
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Is the kernel a trainable parameter?
+    learnable: bool,
+}
+
+/// Three-dimensional max pooling network layer.
+/// 
+/// A max pooling layer `MaxPool3d<B, T, W, H, D, N, X, Y, Z, M, K>` maps volumes of size `N = W*H*D`
+/// and type `T` to volumes of size `M = X*Y*Z` and identical type by taking the largest value in each
+/// cubic window of size `K`.  Volumes are stored as a stack of `D` row-major slices.  `Tensor`s are
+/// evaluated in `Batch`es to improve performance.
+/// 
+/// The `stride` (default `K`) and `padding` (default zero) of this layer behave as for `MaxPool`,
+/// and the output shape must satisfy `X = (W + 2*padding - K)/stride + 1` (and likewise for `Y` and
+/// `Z`).  The backward pass routes each output gradient to the input value which won its window.
+pub struct MaxPool3d<const B: usize, T: Numeric, const W: usize, const H: usize, const D: usize, const N: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> {
+    /// Input index which won each window of the last forward pass, for each tensor of the batch.
+    routes: Vec<usize>,
+
+    /// Step between windows.
+    pub stride: usize,
+
+    /// Padding added to each border of the input.
+    pub padding: usize,
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Input index at padded slice `z`, row `y` and column `x` of a `W*H*D` volume, or `None` if it
+/// lies in the padding.
+fn source<const W: usize, const H: usize, const D: usize>(z: usize, y: usize, x: usize, padding: usize) -> Option<usize> {
+    Some ((unpad(z, D, padding)?*H + unpad(y, H, padding)?)*W + unpad(x, W, padding)?)
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const D: usize, const N: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> AvgPool3d<B, T, W, H, D, N, X, Y, Z, M, K> {
+    /// Construct a new layer whose kernel is a trainable parameter.
+    pub fn learnable() -> Self {
+        Self {
+            learnable: true,
+            ..Layer::new()
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const D: usize, const N: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> Layer<B, T, N, M> for AvgPool3d<B, T, W, H, D, N, X, Y, Z, M, K> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(N == W * H * D, "input size N must be W*H*D");
+            assert!(M == X * Y * Z, "output size M must be X*Y*Z");
+            assert!(K > 0, "pool must not be empty");
+        };
+
+        Self {
+            input: Batch::<B, T, N>::zero(),
+            kernel: [[[T::one() / T::from_f64((K * K * K) as f64); K]; K]; K],
+            kernel_gradient: [[[T::zero(); K]; K]; K],
+            stride: K,
+            padding: 0,
+            learnable: false,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y), (D, Z)]);
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for z in 0..Z {
+                for y in 0..Y {
+                    for x in 0..X {
+                        let o = (z*Y+y)*X+x;
+                        for kz in 0..K {
+                            for ky in 0..K {
+                                for kx in 0..K {
+                                    if let Some (s) = source::<W, H, D>(z*self.stride + kz, y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                        output[o] = output[o] + self.kernel[kz][ky][kx] * input[s];
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y), (D, Z)]);
+
+        let mut result = Batch::<B, T, N>::zero();
+        let b_as_t = T::from_f64(B as f64);
+
+        // Compute gradients of input values and kernel, averaging the latter over the batch
+        self.kernel_gradient = [[[T::zero(); K]; K]; K];
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for z in 0..Z {
+                for y in 0..Y {
+                    for x in 0..X {
+                        let g = gradient[(z*Y+y)*X+x];
+                        for kz in 0..K {
+                            for ky in 0..K {
+                                for kx in 0..K {
+                                    // Overlapping windows accumulate gradients
+                                    if let Some (s) = source::<W, H, D>(z*self.stride + kz, y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                        output[s] = output[s] + self.kernel[kz][ky][kx] * g;
+                                        if self.learnable {
+                                            self.kernel_gradient[kz][ky][kx] = self.kernel_gradient[kz][ky][kx] + input[s] * g / b_as_t;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        if !self.learnable {
+            return Vec::new();
+        }
+
+        vec![
+            Parameter::new(
+                self.kernel.as_flattened_mut().as_flattened_mut(),
+                self.kernel_gradient.as_flattened_mut().as_flattened_mut(),
+            ),
+        ]
+    }
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const D: usize, const N: usize, const X: usize, const Y: usize, const Z: usize, const M: usize, const K: usize> Layer<B, T, N, M> for MaxPool3d<B, T, W, H, D, N, X, Y, Z, M, K> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(N == W * H * D, "input size N must be W*H*D");
+            assert!(M == X * Y * Z, "output size M must be X*Y*Z");
+            assert!(K > 0, "pool must not be empty");
+        };
+
+        Self {
+            routes: vec![0; B*M],
+            stride: K,
+            padding: 0,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        check_shape::<K>(self.stride, self.padding, &[(W, X), (H, Y), (D, Z)]);
+
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for z in 0..Z {
+                for y in 0..Y {
+                    for x in 0..X {
+                        let mut route = None;
+                        for kz in 0..K {
+                            for ky in 0..K {
+                                for kx in 0..K {
+                                    if let Some (s) = source::<W, H, D>(z*self.stride + kz, y*self.stride + ky, x*self.stride + kx, self.padding) {
+                                        let wins = match route {
+                                            Some (r) => input[s] > input[r],
+                                            None => true,
+                                        };
+                                        if wins {
+                                            route = Some (s);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // NOTE padding is at most `K/2`, so every window holds an input value
+                        let o = (z*Y+y)*X+x;
+                        let route = route.unwrap();
+                        self.routes[b*M+o] = route;
+                        output[o] = input[route];
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        // Route each gradient to the winner of its window
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for (&route, &gradient) in self.routes[b*M..(b+1)*M].iter().zip(gradient.iter()) {
+                output[route] = output[route] + gradient;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_pool3d_layers() {
+    // A 4x2x2 volume holding its own flat index
+    let volume = Batch::<1, f64, 16> ([Tensor::<f64, 16>::from_fn(|i| i as f64)]);
+
+    // Each 2x2x2 cube averages its lower corner and upper corner
+    let mut avgpool = AvgPool3d::<1, f64, 4, 2, 2, 16, 2, 1, 1, 2, 2>::new();
+    assert_eq!(avgpool.forward(&volume)[0], Tensor::new([6.5, 8.5]));
+    assert!(avgpool.parameters().is_empty());
+    assert_eq!(AvgPool3d::<1, f64, 4, 2, 2, 16, 2, 1, 1, 2, 2>::learnable().parameter_count(), 8);
+
+    let gradients = Batch::<1, f64, 2> ([Tensor::new([8.0, 16.0])]);
+    assert_eq!(avgpool.backward(&gradients)[0], Tensor::from_fn(|i| if i % 4 < 2 { 1.0 } else { 2.0 }));
+
+    // Each cube is won by its last voxel
+    let mut maxpool = MaxPool3d::<1, f64, 4, 2, 2, 16, 2, 1, 1, 2, 2>::new();
+    assert_eq!(maxpool.forward(&volume)[0], Tensor::new([13.0, 15.0]));
+
+    let mut expected = Tensor::<f64, 16>::zero();
+    expected[13] = 8.0;
+    expected[15] = 16.0;
+    assert_eq!(maxpool.backward(&gradients)[0], expected);
+}
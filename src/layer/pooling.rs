@@ -0,0 +1,29 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Shared window arithmetic for pooling network layers.
+
+/// Check that the output shape of a pool agrees with its stride and padding, given the
+/// input and output size of each axis.
+pub(crate) fn check_shape<const K: usize>(stride: usize, padding: usize, axes: &[(usize, usize)]) {
+    assert!(stride > 0, "stride must be positive");
+    assert!(padding <= K / 2, "padding must be at most K/2");
+    for &(n, x) in axes {
+        assert!(K <= n + 2*padding, "pool must fit in the padded input");
+        assert!(x == (n + 2*padding - K) / stride + 1, "output size must be (n + 2*padding - K)/stride + 1 along each axis of input size n");
+    }
+}
+
+/// Input coordinate at padded coordinate `i` of an axis of size `n`, or `None` if it lies in
+/// the padding.
+pub(crate) fn unpad(i: usize, n: usize, padding: usize) -> Option<usize> {
+    if i < padding || i - padding >= n {
+        return None;
+    }
+    Some (i - padding)
+}
+
+/// Input index at padded row `y` and column `x`, or `None` if it lies in the padding.
+pub(crate) fn source<const W: usize, const H: usize>(y: usize, x: usize, padding: usize) -> Option<usize> {
+    Some (unpad(y, H, padding)?*W + unpad(x, W, padding)?)
+}
@@ -0,0 +1,208 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Gated recurrent unit network layer.
+
+use crate::{
+    layer::{
+        Layer,
+        recurrent::{
+            Gates,
+            sigmoid,
+            tanh,
+            truncated,
+        },
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Reset gate.
+const RESET: usize = 0;
+
+/// Update gate.
+const UPDATE: usize = 1;
+
+/// Candidate state.
+const CANDIDATE: usize = 2;
+
+/// Cached values of one step of the last forward pass.
+#[derive(Clone, Copy)]
+struct Step<T: Numeric, const H: usize> {
+    /// Activations of the reset and update gates and the candidate state.
+    gates: [[T; H]; 3],
+
+    /// Hidden state part `U_n h_{t-1}` of the candidate state, before reset.
+    recurrent: [T; H],
+
+    /// Hidden state.
+    hidden: [T; H],
+}
+
+/// Gated recurrent unit network layer.
+/// 
+/// A GRU layer `Gru<B, T, N, H, L, I, O>` maps sequences of `L` `Tensor`s of size `N`
+/// (so that `I = L*N`) to their hidden states of size `H` (so that `O = L*H`), where
+/// 
+/// - `r_t = sigmoid(W_r x_t + U_r h_{t-1} + b_r)`, and likewise the update gate `z_t`,
+/// - `n_t = tanh(W_n x_t + r_t U_n h_{t-1} + b_n)`, and
+/// - `h_t = (1 - z_t) n_t + z_t h_{t-1}`.
+/// 
+/// Sequences are flattened one step after another, and the hidden state starts at zero.
+/// 
+/// If `truncation` is `Some (k)`, gradients do not flow between chunks of `k` steps.
+pub struct Gru<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, I>,
+
+    /// Cached values of each step of the last forward pass.
+    steps: Vec<[Step<T, H>; L]>,
+
+    /// Layer weights.
+    gates: Gates<T, N, H, 3>,
+
+    /// Number of steps through which to backpropagate, or `None` for the whole sequence.
+    pub truncation: Option<usize>,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> Layer<B, T, I, O> for Gru<B, T, N, H, L, I, O> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(I == L * N, "input size I must be L*N");
+            assert!(O == L * H, "output size O must be L*H");
+        };
+
+        let zero = Step {
+            gates: [[T::zero(); H]; 3],
+            recurrent: [T::zero(); H],
+            hidden: [T::zero(); H],
+        };
+
+        Self {
+            input: Batch::<B, T, I>::zero(),
+            steps: vec![[zero; L]; B],
+            gates: Gates::new(),
+            truncation: None,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, I>) -> Batch<B, T, O> {
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, O>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            let mut h = [T::zero(); H];
+            for t in 0..L {
+                let x = &input[t*N..(t+1)*N];
+                let mut gates = [[T::zero(); H]; 3];
+                for k in [RESET, UPDATE] {
+                    let z = self.gates.preactivation(k, x, &h);
+                    for i in 0..H {
+                        gates[k][i] = sigmoid(z[i]);
+                    }
+                }
+
+                // The reset gate scales the hidden state part of the candidate state
+                let recurrent = self.gates.hidden_part(CANDIDATE, &h);
+                let z = self.gates.input_part(CANDIDATE, x);
+                for i in 0..H {
+                    gates[CANDIDATE][i] = tanh(z[i] + gates[RESET][i] * recurrent[i]);
+                    h[i] = (T::one() - gates[UPDATE][i]) * gates[CANDIDATE][i] + gates[UPDATE][i] * h[i];
+                }
+
+                self.steps[b][t] = Step { gates, recurrent, hidden: h };
+                output[t*H..(t+1)*H].copy_from_slice(&h);
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, O>) -> Batch<B, T, I> {
+        let mut result = Batch::<B, T, I>::zero();
+
+        self.gates.zero_gradients();
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            // Gradient of the hidden state carried from later steps
+            let mut carry = [T::zero(); H];
+            for t in (0..L).rev() {
+                let step = self.steps[b][t];
+                let previous = if t > 0 { self.steps[b][t-1].hidden } else { [T::zero(); H] };
+                let [r_t, z_t, n_t] = step.gates;
+                let x = &input[t*N..(t+1)*N];
+
+                // Gradients of gate preactivations, and of the hidden state part of the
+                // candidate state
+                let mut dz = [[T::zero(); H]; 3];
+                let mut drecurrent = [T::zero(); H];
+                let mut dprevious = [T::zero(); H];
+                for i in 0..H {
+                    let dh = gradient[t*H+i] + carry[i];
+                    dz[CANDIDATE][i] = dh * (T::one() - z_t[i]) * (T::one() - n_t[i] * n_t[i]);
+                    dz[UPDATE][i] = dh * (previous[i] - n_t[i]) * z_t[i] * (T::one() - z_t[i]);
+                    dz[RESET][i] = dz[CANDIDATE][i] * step.recurrent[i] * r_t[i] * (T::one() - r_t[i]);
+                    drecurrent[i] = dz[CANDIDATE][i] * r_t[i];
+                    dprevious[i] = dh * z_t[i];
+                }
+
+                let dx = &mut output[t*N..(t+1)*N];
+                for k in [RESET, UPDATE] {
+                    self.gates.backward(k, &dz[k], x, &previous, dx, &mut dprevious);
+                }
+                self.gates.backward_input(CANDIDATE, &dz[CANDIDATE], x, dx);
+                self.gates.backward_hidden(CANDIDATE, &drecurrent, &previous, &mut dprevious);
+
+                carry = if truncated(self.truncation, t) {
+                    [T::zero(); H]
+                } else {
+                    dprevious
+                };
+            }
+        }
+
+        // Parameter gradients are averaged over the batch
+        self.gates.average_gradients(T::from_f64(B as f64));
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        self.gates.parameters()
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_gru_truncation() {
+    let mut gru = Gru::<1, f64, 1, 2, 4, 4, 8>::new();
+    let sequence = Batch::<1, f64, 4> ([Tensor::new([1.0, -1.0, 0.5, 0.25])]);
+
+    // Only the last hidden state receives a gradient
+    let mut gradients = Batch::<1, f64, 8>::zero();
+    gradients[0][6] = 1.0;
+    gradients[0][7] = 1.0;
+
+    gru.forward(&sequence);
+    let full = gru.backward(&gradients);
+    assert!(full[0].iter().all(|&g| g != 0.0));
+
+    // Gradients do not flow back past step 2
+    gru.truncation = Some (2);
+    let truncated = gru.backward(&gradients);
+    assert_eq!(truncated[0][0], 0.0);
+    assert_eq!(truncated[0][1], 0.0);
+    assert_eq!(truncated[0][2], full[0][2]);
+    assert_eq!(truncated[0][3], full[0][3]);
+}
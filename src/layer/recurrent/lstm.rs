@@ -0,0 +1,215 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Long short-term memory network layer.
+
+use crate::{
+    layer::{
+        Layer,
+        recurrent::{
+            Gates,
+            sigmoid,
+            tanh,
+            truncated,
+        },
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Input gate.
+const INPUT: usize = 0;
+
+/// Forget gate.
+const FORGET: usize = 1;
+
+/// Cell candidate.
+const CELL: usize = 2;
+
+/// Output gate.
+const OUTPUT: usize = 3;
+
+/// Cached values of one step of the last forward pass.
+#[derive(Clone, Copy)]
+struct Step<T: Numeric, const H: usize> {
+    /// Activations of the input, forget and output gates and the cell candidate.
+    gates: [[T; H]; 4],
+
+    /// Cell state.
+    cell: [T; H],
+
+    /// Hyperbolic tangent of cell state.
+    cell_tanh: [T; H],
+
+    /// Hidden state.
+    hidden: [T; H],
+}
+
+/// Long short-term memory network layer.
+/// 
+/// An LSTM layer `Lstm<B, T, N, H, L, I, O>` maps sequences of `L` `Tensor`s of size `N`
+/// (so that `I = L*N`) to their hidden states of size `H` (so that `O = L*H`), where
+/// 
+/// - `i_t = sigmoid(W_i x_t + U_i h_{t-1} + b_i)`, and likewise the forget gate `f_t` and output gate `o_t`,
+/// - `g_t = tanh(W_g x_t + U_g h_{t-1} + b_g)`,
+/// - `c_t = f_t c_{t-1} + i_t g_t`, and
+/// - `h_t = o_t tanh(c_t)`.
+/// 
+/// Sequences are flattened one step after another, and the hidden and cell states start at zero.
+/// 
+/// If `truncation` is `Some (k)`, gradients do not flow between chunks of `k` steps.
+pub struct Lstm<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, I>,
+
+    /// Cached values of each step of the last forward pass.
+    steps: Vec<[Step<T, H>; L]>,
+
+    /// Layer weights.
+    gates: Gates<T, N, H, 4>,
+
+    /// Number of steps through which to backpropagate, or `None` for the whole sequence.
+    pub truncation: Option<usize>,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> Layer<B, T, I, O> for Lstm<B, T, N, H, L, I, O> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(I == L * N, "input size I must be L*N");
+            assert!(O == L * H, "output size O must be L*H");
+        };
+
+        let zero = Step {
+            gates: [[T::zero(); H]; 4],
+            cell: [T::zero(); H],
+            cell_tanh: [T::zero(); H],
+            hidden: [T::zero(); H],
+        };
+
+        Self {
+            input: Batch::<B, T, I>::zero(),
+            steps: vec![[zero; L]; B],
+            gates: Gates::new(),
+            truncation: None,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, I>) -> Batch<B, T, O> {
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, O>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            let mut h = [T::zero(); H];
+            let mut c = [T::zero(); H];
+            for t in 0..L {
+                let x = &input[t*N..(t+1)*N];
+                let mut gates = [[T::zero(); H]; 4];
+                for (k, gate) in gates.iter_mut().enumerate() {
+                    let z = self.gates.preactivation(k, x, &h);
+                    for (gate, &z) in gate.iter_mut().zip(z.iter()) {
+                        *gate = if k == CELL { tanh(z) } else { sigmoid(z) };
+                    }
+                }
+
+                let mut cell_tanh = [T::zero(); H];
+                for i in 0..H {
+                    c[i] = gates[FORGET][i] * c[i] + gates[INPUT][i] * gates[CELL][i];
+                    cell_tanh[i] = tanh(c[i]);
+                    h[i] = gates[OUTPUT][i] * cell_tanh[i];
+                }
+
+                self.steps[b][t] = Step { gates, cell: c, cell_tanh, hidden: h };
+                output[t*H..(t+1)*H].copy_from_slice(&h);
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, O>) -> Batch<B, T, I> {
+        let mut result = Batch::<B, T, I>::zero();
+
+        self.gates.zero_gradients();
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            // Gradients of the hidden and cell states carried from later steps
+            let mut carry_hidden = [T::zero(); H];
+            let mut carry_cell = [T::zero(); H];
+            for t in (0..L).rev() {
+                let step = self.steps[b][t];
+                let (previous_hidden, previous_cell) = if t > 0 {
+                    (self.steps[b][t-1].hidden, self.steps[b][t-1].cell)
+                } else {
+                    ([T::zero(); H], [T::zero(); H])
+                };
+                let [i_t, f_t, g_t, o_t] = step.gates;
+
+                // Gradients of gate preactivations
+                let mut dz = [[T::zero(); H]; 4];
+                for i in 0..H {
+                    let dh = gradient[t*H+i] + carry_hidden[i];
+                    let dc = dh * o_t[i] * (T::one() - step.cell_tanh[i] * step.cell_tanh[i]) + carry_cell[i];
+
+                    dz[INPUT][i] = dc * g_t[i] * i_t[i] * (T::one() - i_t[i]);
+                    dz[FORGET][i] = dc * previous_cell[i] * f_t[i] * (T::one() - f_t[i]);
+                    dz[CELL][i] = dc * i_t[i] * (T::one() - g_t[i] * g_t[i]);
+                    dz[OUTPUT][i] = dh * step.cell_tanh[i] * o_t[i] * (T::one() - o_t[i]);
+                    carry_cell[i] = dc * f_t[i];
+                }
+
+                carry_hidden = [T::zero(); H];
+                for (k, dz) in dz.iter().enumerate() {
+                    self.gates.backward(k, dz, &input[t*N..(t+1)*N], &previous_hidden, &mut output[t*N..(t+1)*N], &mut carry_hidden);
+                }
+                if truncated(self.truncation, t) {
+                    carry_hidden = [T::zero(); H];
+                    carry_cell = [T::zero(); H];
+                }
+            }
+        }
+
+        // Parameter gradients are averaged over the batch
+        self.gates.average_gradients(T::from_f64(B as f64));
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        self.gates.parameters()
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_lstm_truncation() {
+    let mut lstm = Lstm::<1, f64, 1, 2, 4, 4, 8>::new();
+    let sequence = Batch::<1, f64, 4> ([Tensor::new([1.0, -1.0, 0.5, 0.25])]);
+
+    // Only the last hidden state receives a gradient
+    let mut gradients = Batch::<1, f64, 8>::zero();
+    gradients[0][6] = 1.0;
+    gradients[0][7] = 1.0;
+
+    lstm.forward(&sequence);
+    let full = lstm.backward(&gradients);
+    assert!(full[0].iter().all(|&g| g != 0.0));
+
+    // Gradients do not flow back past step 2, through the hidden or the cell state
+    lstm.truncation = Some (2);
+    let truncated = lstm.backward(&gradients);
+    assert_eq!(truncated[0][0], 0.0);
+    assert_eq!(truncated[0][1], 0.0);
+    assert_eq!(truncated[0][2], full[0][2]);
+    assert_eq!(truncated[0][3], full[0][3]);
+}
@@ -0,0 +1,193 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Recurrent network layers.
+//! 
+//! Recurrent layers map a sequence of `L` `Tensor`s of size `N`, flattened one step after
+//! another into a `Tensor` of size `L*N`, to the sequence of their `L` hidden states of
+//! size `H`, flattened into a `Tensor` of size `L*H`.  The hidden state starts at zero for
+//! each sequence.
+//! 
+//! Gradients are computed by backpropagation through time.  If a layer's `truncation` is
+//! `Some (k)`, gradients do not flow between the chunks of `k` steps starting at steps
+//! `0, k, 2k, ...`, as if the hidden state entering each chunk were constant.
+
+mod gru;
+mod lstm;
+mod rnn;
+
+use crate::{
+    Numeric,
+    optim::Parameter,
+};
+
+pub use gru::Gru;
+pub use lstm::Lstm;
+pub use rnn::Rnn;
+
+/// Weights of `G` gates, each an affine map of the step input and the previous hidden state.
+pub(crate) struct Gates<T: Numeric, const N: usize, const H: usize, const G: usize> {
+    /// Input weights of each gate.
+    input: [[[T; N]; H]; G],
+
+    /// Hidden state weights of each gate.
+    hidden: [[[T; H]; H]; G],
+
+    /// Bias of each gate.
+    bias: [[T; H]; G],
+
+    /// Gradients of input weights.
+    input_gradient: [[[T; N]; H]; G],
+
+    /// Gradients of hidden state weights.
+    hidden_gradient: [[[T; H]; H]; G],
+
+    /// Gradients of bias.
+    bias_gradient: [[T; H]; G],
+}
+
+impl<T: Numeric, const N: usize, const H: usize, const G: usize> Gates<T, N, H, G> {
+    /// Construct new gates, setting all weights uniformly in `[-1/sqrt(H), 1/sqrt(H))`.
+    pub(crate) fn new() -> Self {
+        let scale = T::one() / T::from_f64(H as f64).sqrt();
+        let random = || (T::from_f64(2.0) * T::random() - T::one()) * scale;
+
+        let mut gates = Self {
+            input: [[[T::zero(); N]; H]; G],
+            hidden: [[[T::zero(); H]; H]; G],
+            bias: [[T::zero(); H]; G],
+            input_gradient: [[[T::zero(); N]; H]; G],
+            hidden_gradient: [[[T::zero(); H]; H]; G],
+            bias_gradient: [[T::zero(); H]; G],
+        };
+        for k in 0..G {
+            for i in 0..H {
+                for j in 0..N {
+                    gates.input[k][i][j] = random();
+                }
+                for j in 0..H {
+                    gates.hidden[k][i][j] = random();
+                }
+                gates.bias[k][i] = random();
+            }
+        }
+        gates
+    }
+
+    /// Input part `W x + b` of the preactivation of gate `k`.
+    pub(crate) fn input_part(&self, k: usize, x: &[T]) -> [T; H] {
+        let mut z = self.bias[k];
+        for (z, row) in z.iter_mut().zip(self.input[k].iter()) {
+            for (&weight, &x) in row.iter().zip(x.iter()) {
+                *z = *z + weight * x;
+            }
+        }
+        z
+    }
+
+    /// Hidden state part `U h` of the preactivation of gate `k`.
+    pub(crate) fn hidden_part(&self, k: usize, h: &[T; H]) -> [T; H] {
+        let mut z = [T::zero(); H];
+        for (z, row) in z.iter_mut().zip(self.hidden[k].iter()) {
+            for (&weight, &h) in row.iter().zip(h.iter()) {
+                *z = *z + weight * h;
+            }
+        }
+        z
+    }
+
+    /// Preactivation `W x + U h + b` of gate `k`.
+    pub(crate) fn preactivation(&self, k: usize, x: &[T], h: &[T; H]) -> [T; H] {
+        let mut z = self.input_part(k, x);
+        let u = self.hidden_part(k, h);
+        for (z, u) in z.iter_mut().zip(u.iter()) {
+            *z = *z + *u;
+        }
+        z
+    }
+
+    /// Reset all gradients to zero.
+    pub(crate) fn zero_gradients(&mut self) {
+        self.input_gradient = [[[T::zero(); N]; H]; G];
+        self.hidden_gradient = [[[T::zero(); H]; H]; G];
+        self.bias_gradient = [[T::zero(); H]; G];
+    }
+
+    /// Backpropagate the gradient `dz` of the input part of gate `k`, accumulating
+    /// parameter gradients and input gradients into `dx`.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn backward_input(&mut self, k: usize, dz: &[T; H], x: &[T], dx: &mut [T]) {
+        for i in 0..H {
+            for j in 0..N {
+                self.input_gradient[k][i][j] = self.input_gradient[k][i][j] + dz[i] * x[j];
+                dx[j] = dx[j] + self.input[k][i][j] * dz[i];
+            }
+            self.bias_gradient[k][i] = self.bias_gradient[k][i] + dz[i];
+        }
+    }
+
+    /// Backpropagate the gradient `dz` of the hidden state part of gate `k`, accumulating
+    /// parameter gradients and hidden state gradients into `dh`.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn backward_hidden(&mut self, k: usize, dz: &[T; H], h: &[T; H], dh: &mut [T; H]) {
+        for i in 0..H {
+            for j in 0..H {
+                self.hidden_gradient[k][i][j] = self.hidden_gradient[k][i][j] + dz[i] * h[j];
+                dh[j] = dh[j] + self.hidden[k][i][j] * dz[i];
+            }
+        }
+    }
+
+    /// Backpropagate the gradient `dz` of the preactivation of gate `k`.
+    pub(crate) fn backward(&mut self, k: usize, dz: &[T; H], x: &[T], h: &[T; H], dx: &mut [T], dh: &mut [T; H]) {
+        self.backward_input(k, dz, x, dx);
+        self.backward_hidden(k, dz, h, dh);
+    }
+
+    /// Divide all gradients by `count`, e.g. to average them over a batch.
+    pub(crate) fn average_gradients(&mut self, count: T) {
+        for gradient in self.input_gradient.as_flattened_mut().as_flattened_mut() {
+            *gradient = *gradient / count;
+        }
+        for gradient in self.hidden_gradient.as_flattened_mut().as_flattened_mut() {
+            *gradient = *gradient / count;
+        }
+        for gradient in self.bias_gradient.as_flattened_mut() {
+            *gradient = *gradient / count;
+        }
+    }
+
+    /// Get the weights of these gates, along with their gradients.
+    pub(crate) fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        vec![
+            Parameter::new(
+                self.input.as_flattened_mut().as_flattened_mut(),
+                self.input_gradient.as_flattened_mut().as_flattened_mut(),
+            ),
+            Parameter::new(
+                self.hidden.as_flattened_mut().as_flattened_mut(),
+                self.hidden_gradient.as_flattened_mut().as_flattened_mut(),
+            ),
+            Parameter::new(self.bias.as_flattened_mut(), self.bias_gradient.as_flattened_mut()),
+        ]
+    }
+}
+
+/// Logistic sigmoid.
+pub(crate) fn sigmoid<T: Numeric>(x: T) -> T {
+    T::one() / (T::one() + (-x).exp())
+}
+
+/// Hyperbolic tangent.
+pub(crate) fn tanh<T: Numeric>(x: T) -> T {
+    let two = T::one() + T::one();
+    two * sigmoid(two * x) - T::one()
+}
+
+/// Does the gradient of the hidden state stop at step `t`, the start of a truncation chunk?
+pub(crate) fn truncated(truncation: Option<usize>, t: usize) -> bool {
+    match truncation {
+        Some (k) => t.is_multiple_of(k),
+        None => false,
+    }
+}
@@ -0,0 +1,146 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Vanilla recurrent network layer.
+
+use crate::{
+    layer::{
+        Layer,
+        recurrent::{
+            Gates,
+            tanh,
+            truncated,
+        },
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Vanilla recurrent network layer.
+/// 
+/// A recurrent layer `Rnn<B, T, N, H, L, I, O>` maps sequences of `L` `Tensor`s of size `N`
+/// (so that `I = L*N`) to their hidden states `h_t = tanh(W x_t + U h_{t-1} + b)` of size `H`
+/// (so that `O = L*H`).  Sequences are flattened one step after another, and the hidden state
+/// starts at zero.
+/// 
+/// If `truncation` is `Some (k)`, gradients do not flow between chunks of `k` steps.
+pub struct Rnn<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> {
+    /// Last network layer input.
+    input: Batch<B, T, I>,
+
+    /// Hidden state after each step of the last forward pass, for each sequence of the batch.
+    /// 
+    /// NOTE the initial state is zero and not stored.
+    states: Vec<[[T; H]; L]>,
+
+    /// Layer weights.
+    gates: Gates<T, N, H, 1>,
+
+    /// Number of steps through which to backpropagate, or `None` for the whole sequence.
+    pub truncation: Option<usize>,
+}
+
+impl<const B: usize, T: Numeric, const N: usize, const H: usize, const L: usize, const I: usize, const O: usize> Layer<B, T, I, O> for Rnn<B, T, N, H, L, I, O> {
+    /// Construct a new layer, setting all parameters randomly.
+    fn new() -> Self {
+        const {
+            assert!(I == L * N, "input size I must be L*N");
+            assert!(O == L * H, "output size O must be L*H");
+        };
+
+        Self {
+            input: Batch::<B, T, I>::zero(),
+            states: vec![[[T::zero(); H]; L]; B],
+            gates: Gates::new(),
+            truncation: None,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, I>) -> Batch<B, T, O> {
+        self.input = batch.clone();
+
+        let mut result = Batch::<B, T, O>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            let mut h = [T::zero(); H];
+            for t in 0..L {
+                let z = self.gates.preactivation(0, &input[t*N..(t+1)*N], &h);
+                for i in 0..H {
+                    h[i] = tanh(z[i]);
+                }
+                self.states[b][t] = h;
+                output[t*H..(t+1)*H].copy_from_slice(&h);
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, O>) -> Batch<B, T, I> {
+        let mut result = Batch::<B, T, I>::zero();
+
+        self.gates.zero_gradients();
+        for b in 0..B {
+            let input = self.input[b].as_array();
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            // Gradient of the hidden state carried from later steps
+            let mut carry = [T::zero(); H];
+            for t in (0..L).rev() {
+                let h = self.states[b][t];
+                let previous = if t > 0 { self.states[b][t-1] } else { [T::zero(); H] };
+
+                let mut dz = [T::zero(); H];
+                for i in 0..H {
+                    dz[i] = (gradient[t*H+i] + carry[i]) * (T::one() - h[i] * h[i]);
+                }
+
+                carry = [T::zero(); H];
+                self.gates.backward(0, &dz, &input[t*N..(t+1)*N], &previous, &mut output[t*N..(t+1)*N], &mut carry);
+                if truncated(self.truncation, t) {
+                    carry = [T::zero(); H];
+                }
+            }
+        }
+
+        // Parameter gradients are averaged over the batch
+        self.gates.average_gradients(T::from_f64(B as f64));
+
+        result
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        self.gates.parameters()
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_rnn_truncation() {
+    let mut rnn = Rnn::<1, f64, 1, 2, 4, 4, 8>::new();
+    let sequence = Batch::<1, f64, 4> ([Tensor::new([1.0, -1.0, 0.5, 0.25])]);
+
+    // Only the last hidden state receives a gradient
+    let mut gradients = Batch::<1, f64, 8>::zero();
+    gradients[0][6] = 1.0;
+    gradients[0][7] = 1.0;
+
+    rnn.forward(&sequence);
+    let full = rnn.backward(&gradients);
+    assert!(full[0].iter().all(|&g| g != 0.0));
+
+    // Gradients do not flow back past step 2
+    rnn.truncation = Some (2);
+    let truncated = rnn.backward(&gradients);
+    assert_eq!(truncated[0][0], 0.0);
+    assert_eq!(truncated[0][1], 0.0);
+    assert_eq!(truncated[0][2], full[0][2]);
+    assert_eq!(truncated[0][3], full[0][3]);
+}
@@ -0,0 +1,94 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Depthwise-separable convolutional network block.
+
+use crate::{
+    layer::{
+        Conv2d,
+        GroupedConv2d,
+        Layer,
+    },
+    Numeric,
+    optim::Parameter,
+    tensor::Batch,
+};
+
+/// Depthwise-separable convolutional network block.
+/// 
+/// A depthwise-separable block `DepthwiseSeparable<B, T, C, W, H, N, F, X, Y, D, M, K>` maps
+/// `Tensor`s of `C` channels of size `W*H`, so that `N = C*W*H`, to `Tensor`s of `F` channels
+/// of size `X*Y`, so that `M = F*X*Y`.  A depthwise convolution first filters each channel
+/// separately with its own square kernel of size `K`, giving `D = C*X*Y` values, and a
+/// pointwise convolution with unit kernels then mixes the channels.  This factorization of
+/// a `Conv2d` needs `C*K*K + F*C` rather than `F*C*K*K` weights.
+/// 
+/// The `stride`, `padding`, `dilation` and `padding_mode` of the `depthwise` convolution may
+/// be set after construction, as for `Conv2d`.
+pub struct DepthwiseSeparable<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const D: usize, const M: usize, const K: usize> {
+    /// Per-channel convolution.
+    pub depthwise: GroupedConv2d<B, T, C, W, H, N, C, X, Y, D, K, C, 1>,
+
+    /// Channel-mixing convolution.
+    pub pointwise: Conv2d<B, T, C, X, Y, D, F, X, Y, M, 1>,
+}
+
+impl<const B: usize, T: Numeric, const C: usize, const W: usize, const H: usize, const N: usize, const F: usize, const X: usize, const Y: usize, const D: usize, const M: usize, const K: usize> Layer<B, T, N, M> for DepthwiseSeparable<B, T, C, W, H, N, F, X, Y, D, M, K> {
+    /// Construct a new block, setting all parameters randomly.
+    fn new() -> Self {
+        Self {
+            depthwise: GroupedConv2d::new(),
+            pointwise: Conv2d::new(),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        let filtered = self.depthwise.forward(batch);
+        self.pointwise.forward(&filtered)
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        let gradients = self.pointwise.backward(gradients);
+        self.depthwise.backward(&gradients)
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, T>> {
+        let mut parameters = self.depthwise.parameters();
+        parameters.extend(self.pointwise.parameters());
+        parameters
+    }
+}
+
+#[cfg(test)]
+use crate::{
+    tensor::Tensor,
+    x16,
+};
+
+#[test]
+fn test_depthwise_separable() {
+    // Factorized block is much smaller than the full convolution
+    let mut separable = DepthwiseSeparable::<1, f64, 4, 5, 5, 100, 8, 3, 3, 36, 72, 3>::new();
+    let mut full = Conv2d::<1, f64, 4, 5, 5, 100, 8, 3, 3, 72, 3>::new();
+    assert_eq!(separable.parameter_count(), (4*3*3 + 4) + (8*4 + 8));
+    assert_eq!(full.parameter_count(), 8*4*3*3 + 8);
+
+    // Blur each channel, then sum channels pairwise
+    let mut block = DepthwiseSeparable::<1, x16, 2, 3, 3, 18, 1, 2, 2, 8, 4, 2>::new();
+    block.depthwise.kernel = [[[[x16::from(0.25f32); 2]; 2]]; 2];
+    block.depthwise.bias = [x16::from(0.0f32); 2];
+    block.pointwise.kernel = [[[[x16::from(1.0f32)]], [[x16::from(-1.0f32)]]]];
+    block.pointwise.bias = [x16::from(0.5f32)];
+
+    let image = Batch::<1, x16, 18> ([Tensor::new([
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+
+        1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+    ].map(x16::from))]);
+    let result = block.forward(&image);
+    assert_eq!(result[0], Tensor::new([2.5, 3.5, 5.5, 6.5].map(x16::from)));
+}
@@ -0,0 +1,154 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Upsampling network layer.
+
+use crate::{
+    layer::Layer,
+    Numeric,
+    tensor::Batch,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Interpolation used to resample an image.
+pub enum Interpolation {
+    /// Copy the nearest input value.
+    #[default]
+    Nearest,
+
+    /// Interpolate linearly between the four nearest input values, treating values as
+    /// pixel centers.
+    Bilinear,
+}
+
+/// Upsampling network layer.
+/// 
+/// An upsampling layer `Upsample<B, T, W, H, N, X, Y, M>` resizes `Tensor`s of size `N = W*H` and type `T`
+/// to `Tensor`s of size `M = X*Y` and identical type using the given `mode` of interpolation (default
+/// nearest), where `X >= W` and `Y >= H`.  `Tensor`s are evaluated in `Batch`es to improve performance.
+pub struct Upsample<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize> {
+    /// Interpolation of output values.
+    pub mode: Interpolation,
+
+    /// Phantom type marker.
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize> Upsample<B, T, W, H, N, X, Y, M> {
+    /// Input indices read by output `(y, x)`, along with their weights.
+    fn taps(&self, y: usize, x: usize) -> [(usize, T); 4] {
+        let (y0, y1, ly) = source(self.mode, y, H, Y);
+        let (x0, x1, lx) = source(self.mode, x, W, X);
+        [
+            (y0*W+x0, T::from_f64((1.0 - ly) * (1.0 - lx))),
+            (y0*W+x1, T::from_f64((1.0 - ly) * lx)),
+            (y1*W+x0, T::from_f64(ly * (1.0 - lx))),
+            (y1*W+x1, T::from_f64(ly * lx)),
+        ]
+    }
+}
+
+impl<const B: usize, T: Numeric, const W: usize, const H: usize, const N: usize, const X: usize, const Y: usize, const M: usize> Layer<B, T, N, M> for Upsample<B, T, W, H, N, X, Y, M> {
+    /// Construct a new layer.
+    fn new() -> Self {
+        const {
+            assert!(N == W * H, "input size N must be W*H");
+            assert!(M == X * Y, "output size M must be X*Y");
+            assert!(W > 0 && H > 0, "input must not be empty");
+            assert!(X >= W && Y >= H, "output must be at least as large as input");
+        };
+
+        Self {
+            mode: Interpolation::Nearest,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<B, T, N>) -> Batch<B, T, M> {
+        let mut result = Batch::<B, T, M>::zero();
+
+        for b in 0..B {
+            let input = batch[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for y in 0..Y {
+                for x in 0..X {
+                    for (i, weight) in self.taps(y, x) {
+                        output[y*X+x] = output[y*X+x] + weight * input[i];
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn backward(&mut self, gradients: &Batch<B, T, M>) -> Batch<B, T, N> {
+        let mut result = Batch::<B, T, N>::zero();
+
+        // Each output value is a weighted sum of input values
+        for b in 0..B {
+            let gradient = gradients[b].as_array();
+            let output = result[b].as_mut_array();
+
+            for y in 0..Y {
+                for x in 0..X {
+                    for (i, weight) in self.taps(y, x) {
+                        output[i] = output[i] + weight * gradient[y*X+x];
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Neighboring input coordinates of output coordinate `o` along an axis resized from `n`
+/// to `m`, along with the weight of the second.
+fn source(mode: Interpolation, o: usize, n: usize, m: usize) -> (usize, usize, f64) {
+    match mode {
+        Interpolation::Nearest => {
+            let i = (o * n / m).min(n - 1);
+            (i, i, 0.0)
+        },
+        Interpolation::Bilinear => {
+            // Align pixel centers, clamping at the borders
+            let s = ((o as f64 + 0.5) * n as f64 / m as f64 - 0.5).max(0.0);
+            let i = (s.floor() as usize).min(n - 1);
+            let j = (i + 1).min(n - 1);
+            (i, j, s - i as f64)
+        },
+    }
+}
+
+#[cfg(test)]
+use crate::tensor::Tensor;
+
+#[test]
+fn test_upsample_layer() {
+    let image = Batch::<1, f64, 4> ([Tensor::<f64, 4>::new([
+        0.0, 4.0,
+        8.0, 12.0,
+    ])]);
+
+    let mut upsample = Upsample::<1, f64, 2, 2, 4, 4, 4, 16>::new();
+    assert_eq!(upsample.forward(&image)[0], Tensor::new([
+        0.0, 0.0, 4.0, 4.0,
+        0.0, 0.0, 4.0, 4.0,
+        8.0, 8.0, 12.0, 12.0,
+        8.0, 8.0, 12.0, 12.0,
+    ]));
+
+    upsample.mode = Interpolation::Bilinear;
+    assert_eq!(upsample.forward(&image)[0], Tensor::new([
+        0.0, 1.0, 3.0, 4.0,
+        2.0, 3.0, 5.0, 6.0,
+        6.0, 7.0, 9.0, 10.0,
+        8.0, 9.0, 11.0, 12.0,
+    ]));
+
+    // Every input value is spread over four output values in total
+    let gradients = Batch::<1, f64, 16> ([Tensor::new([1.0; 16])]);
+    assert_eq!(upsample.backward(&gradients)[0], Tensor::new([4.0; 4]));
+}
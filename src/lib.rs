@@ -7,18 +7,41 @@
 mod activation;
 mod architecture;
 mod dataset;
+mod differentiation;
 mod hyperparameters;
 mod layer;
 mod loss;
 mod numeric;
+mod optimizer;
+mod random;
+mod schedule;
 mod tensors;
 
 pub use crate::numeric::Numeric;
 
 pub use crate::numeric::x16;
 
+pub use crate::random::seed;
+
+pub mod autodiff {
+    pub use crate::differentiation::Dual;
+    pub use crate::differentiation::Function;
+    pub use crate::differentiation::GradCheck;
+    pub use crate::differentiation::Gradients;
+    pub use crate::differentiation::Graph;
+    pub use crate::differentiation::Var;
+    pub use crate::differentiation::gradcheck;
+    pub use crate::differentiation::gradcheck_activation;
+    pub use crate::differentiation::gradcheck_loss;
+    pub use crate::differentiation::hessian;
+    pub use crate::differentiation::hessian_diagonal;
+    pub use crate::differentiation::hvp;
+    pub use crate::differentiation::jvp;
+}
+
 pub mod models {
     pub use crate::architecture::Architecture;
+    pub use crate::architecture::Lift;
 
     // Predefined classifiers
     pub use crate::architecture::classifiers;
@@ -39,9 +62,37 @@ pub mod network {
     pub use crate::layer::Layer;
 
     pub mod layer {
+        pub use crate::layer::Autograd;
         pub use crate::layer::AvgPool;
+        pub use crate::layer::AvgPool1d;
+        pub use crate::layer::AvgPool3d;
+        pub use crate::layer::BatchNorm;
+        pub use crate::layer::Conv1d;
+        pub use crate::layer::Conv2d;
+        pub use crate::layer::Conv3d;
         pub use crate::layer::Convolution;
+        pub use crate::layer::ConvTranspose;
+        pub use crate::layer::DepthwiseSeparable;
+        pub use crate::layer::Dropout;
+        pub use crate::layer::Embedding;
+        pub use crate::layer::GlobalAvgPool;
+        pub use crate::layer::GlobalMaxPool;
+        pub use crate::layer::GroupedConv1d;
+        pub use crate::layer::GroupedConv2d;
+        pub use crate::layer::GroupedConv3d;
+        pub use crate::layer::Gru;
+        pub use crate::layer::Interpolation;
+        pub use crate::layer::LayerNorm;
         pub use crate::layer::Linear;
+        pub use crate::layer::Lstm;
+        pub use crate::layer::MaxPool;
+        pub use crate::layer::MaxPool1d;
+        pub use crate::layer::MaxPool3d;
+        pub use crate::layer::MinPool;
+        pub use crate::layer::MultiHeadAttention;
+        pub use crate::layer::Padding;
+        pub use crate::layer::Rnn;
+        pub use crate::layer::Upsample;
     }
 }
 
@@ -50,6 +101,25 @@ pub mod optim {
 
     pub use crate::loss::Loss;
 
+    pub use crate::optimizer::Algorithm;
+    pub use crate::optimizer::Minimizer;
+    pub use crate::optimizer::Optimizer;
+    pub use crate::optimizer::Parameter;
+
+    pub use crate::schedule::LrSchedule;
+
+    // Optimizers
+    pub use crate::optimizer::Adagrad;
+    pub use crate::optimizer::Adam;
+    pub use crate::optimizer::AdamW;
+    pub use crate::optimizer::GradientDescent;
+    pub use crate::optimizer::RMSProp;
+    pub use crate::optimizer::SGD;
+
+    // Full-batch minimizers
+    pub use crate::optimizer::ConjugateGradient;
+    pub use crate::optimizer::LBFGS;
+
     pub mod loss {
         pub use crate::loss::CrossEntropyLoss;
         pub use crate::loss::MSELoss;
@@ -59,5 +129,8 @@ pub mod optim {
 pub mod tensor {
     pub use crate::dataset::Dataset;
     pub use crate::tensors::Tensor;
+    pub use crate::tensors::Tensor2;
+    pub use crate::tensors::Tensor3;
     pub use crate::tensors::Batch;
+    pub use crate::tensors::DynTensor;
 }
@@ -24,33 +24,16 @@ impl<const B: usize, T: Numeric, const N: usize> Loss<B, T, N> for MSELoss<B, T,
     }
 
     fn forward(&mut self, prediction: &Batch<B, T, N>, labels: &Batch<B, T, N>) -> T {
-        self.prediction = *prediction;
-        self.labels = *labels;
+        self.prediction = prediction.clone();
+        self.labels = labels.clone();
 
-        // Initialize result
-        let mut loss = T::zero();
-        let mut b_as_t = T::zero();
-
-        for b in 0..B {
-            for i in 0..N {
-                loss = loss + (prediction[b][i] - labels[b][i]) * (prediction[b][i] - labels[b][i]);
-            }
-            b_as_t = b_as_t + T::one();
-        }
-
-        loss / b_as_t
+        let error = prediction - labels;
+        (&error * &error).sum().sum() / T::from_f64(B as f64)
     }
 
     fn backward(&self) -> Batch<B, T, N> {
         // Backpropagate gradients
-        let mut backward = Batch::<B, T, N>::zero();
         let two = T::one() + T::one();
-        for b in 0..B {
-            for i in 0..N {
-                backward[b][i] = two * (self.prediction[b][i] - self.labels[b][i]);
-            }
-        }
-
-        backward
+        (&self.prediction - &self.labels) * two
     }
 }
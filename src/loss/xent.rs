@@ -27,7 +27,7 @@ impl<const B: usize, T: Numeric, const N: usize> Loss<B, T, N> for CrossEntropyL
     }
 
     fn forward(&mut self, prediction: &Batch<B, T, N>, labels: &Batch<B, T, N>) -> T {
-        self.labels = *labels;
+        self.labels = labels.clone();
 
         // Initialize result
         let mut loss = T::zero();
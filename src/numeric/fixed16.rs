@@ -14,7 +14,7 @@ use std::{
     },
 };
 
-use rand::random;
+use crate::random::uniform;
 
 /// Scale of 16-bit float.
 const SCALE: i16 = 1_000;
@@ -27,7 +27,7 @@ pub struct x16 (i16);
 impl x16 {
     /// Generate a random value between 0 and 1.
     pub fn random() -> Self {
-        let r = SCALE as f32 * random::<f32>();
+        let r = SCALE as f32 * uniform::<f32>();
         Self (r as i16)
     }
 
@@ -60,6 +60,16 @@ impl x16 {
     pub fn log(self) -> Self {
         Self (((self.0 as f32).ln() - (SCALE as f32).ln()) as i16)
     }
+
+    /// Square root.
+    pub fn sqrt(self) -> Self {
+        Self (((self.0 as f32) * SCALE as f32).sqrt() as i16)
+    }
+
+    /// Convert to a 64-bit float.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / SCALE as f64
+    }
 }
 
 impl Add<x16> for x16 {
@@ -16,8 +16,6 @@ use std::{
     },
 };
 
-use rand;
-
 pub use fixed16::x16;
 
 /// Numeric data type.
@@ -25,6 +23,7 @@ pub use fixed16::x16;
 /// A data type `T` can be `Numeric` if it defines the following.
 /// - Addition over itself: `T + T -> T`
 /// - Subtraction
+///
 /// TODO
 pub trait Numeric: Clone + Copy + Add<Output = Self> + Mul<Output = Self> + Sub<Output = Self> + Div<Output = Self> + Neg<Output = Self> + PartialOrd + Debug {
     fn zero() -> Self;
@@ -35,6 +34,12 @@ pub trait Numeric: Clone + Copy + Add<Output = Self> + Mul<Output = Self> + Sub<
 
     fn log(self) -> Self;
 
+    fn sqrt(self) -> Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
     fn random() -> Self;
 
     fn tiny() -> Self;
@@ -59,8 +64,20 @@ impl Numeric for f32 {
         self.ln()
     }
 
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
     fn random() -> Self {
-        rand::random()
+        crate::random::uniform()
     }
 
     fn tiny() -> Self {
@@ -89,8 +106,20 @@ impl Numeric for f64 {
         self.ln()
     }
 
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
     fn random() -> Self {
-        rand::random()
+        crate::random::uniform()
     }
 
     fn tiny() -> Self {
@@ -119,6 +148,18 @@ impl Numeric for x16 {
         self.log()
     }
 
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::from(value as f32)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_f64()
+    }
+
     fn random() -> Self {
         Self::random()
     }
@@ -0,0 +1,62 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Adagrad optimizer.
+
+use crate::{
+    Numeric,
+    optim::{
+        Optimizer,
+        Parameter,
+    },
+    optimizer::{
+        allocate,
+        epsilon,
+    },
+};
+
+#[derive(Clone, Debug)]
+/// Adagrad optimizer.
+/// 
+/// Each parameter accumulates the sum `s` of its squared gradients over all steps,
+/// and is updated as `w - lr * g / (sqrt(s) + epsilon)`.
+pub struct Adagrad<T: Numeric> {
+    /// Small value added to the denominator for numerical stability.
+    pub epsilon: T,
+
+    /// Sum of squared gradients of each parameter.
+    square_sum: Vec<Vec<T>>,
+}
+
+impl<T: Numeric> Adagrad<T> {
+    /// Construct a new Adagrad optimizer with the given epsilon.
+    pub fn new(epsilon: T) -> Self {
+        Self {
+            epsilon,
+            square_sum: Vec::new(),
+        }
+    }
+}
+
+impl<T: Numeric> Default for Adagrad<T> {
+    /// Construct a new Adagrad optimizer with `epsilon = 1e-8`.
+    fn default() -> Self {
+        Self::new(epsilon())
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for Adagrad<T> {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        allocate(&mut self.square_sum, parameters);
+
+        for (p, parameter) in parameters.iter_mut().enumerate() {
+            let s = &mut self.square_sum[p];
+
+            for i in parameter.indices() {
+                let g = parameter.gradient[i];
+                s[i] = s[i] + g * g;
+                parameter.value[i] = parameter.value[i] - lr * g / (s[i].sqrt() + self.epsilon);
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Adam and AdamW optimizers.
+
+use crate::{
+    Numeric,
+    optim::{
+        Optimizer,
+        Parameter,
+    },
+    optimizer::{
+        allocate,
+        epsilon,
+    },
+};
+
+#[derive(Clone, Debug)]
+/// Adam optimizer.
+/// 
+/// Adam keeps exponential moving averages of the gradient (first moment) and
+/// of the squared gradient (second moment) of every parameter value, and scales
+/// each update by their bias-corrected ratio.
+/// 
+/// Moment buffers are allocated on the first call to `Optimizer::step`, one for
+/// each `Parameter` passed (e.g. the weights and bias of each `Linear` layer).
+pub struct Adam<T: Numeric> {
+    /// Decay rate of the first moment estimate.
+    pub beta1: T,
+
+    /// Decay rate of the second moment estimate.
+    pub beta2: T,
+
+    /// Small value added to the denominator for numerical stability.
+    pub epsilon: T,
+
+    /// First moment estimate of each parameter.
+    m: Vec<Vec<T>>,
+
+    /// Second moment estimate of each parameter.
+    v: Vec<Vec<T>>,
+
+    /// `beta1` raised to the number of steps taken.
+    beta1_t: T,
+
+    /// `beta2` raised to the number of steps taken.
+    beta2_t: T,
+}
+
+impl<T: Numeric> Adam<T> {
+    /// Construct a new Adam optimizer with the given decay rates and epsilon.
+    pub fn new(beta1: T, beta2: T, epsilon: T) -> Self {
+        Self {
+            beta1,
+            beta2,
+            epsilon,
+            m: Vec::new(),
+            v: Vec::new(),
+            beta1_t: T::one(),
+            beta2_t: T::one(),
+        }
+    }
+
+    /// Update the given parameters, decaying each value by `lr * weight_decay`
+    /// before applying the Adam step.
+    fn update(&mut self, parameters: &mut [Parameter<'_, T>], lr: T, weight_decay: T) {
+        // Allocate moment buffers for parameters not yet seen
+        allocate(&mut self.m, parameters);
+        allocate(&mut self.v, parameters);
+
+        // Bias correction terms
+        self.beta1_t = self.beta1_t * self.beta1;
+        self.beta2_t = self.beta2_t * self.beta2;
+        let correction1 = T::one() - self.beta1_t;
+        let correction2 = T::one() - self.beta2_t;
+
+        for (p, parameter) in parameters.iter_mut().enumerate() {
+            let m = &mut self.m[p];
+            let v = &mut self.v[p];
+
+            for i in parameter.indices() {
+                let g = parameter.gradient[i];
+
+                // Update moment estimates
+                m[i] = self.beta1 * m[i] + (T::one() - self.beta1) * g;
+                v[i] = self.beta2 * v[i] + (T::one() - self.beta2) * g * g;
+
+                let m_hat = m[i] / correction1;
+                let v_hat = v[i] / correction2;
+
+                // Decoupled weight decay, then Adam step
+                let value = parameter.value[i] - lr * weight_decay * parameter.value[i];
+                parameter.value[i] = value - lr * m_hat / (v_hat.sqrt() + self.epsilon);
+            }
+        }
+    }
+}
+
+impl<T: Numeric> Default for Adam<T> {
+    /// Construct a new Adam optimizer with `beta1 = 0.9`, `beta2 = 0.999` and
+    /// `epsilon = 1e-8`.
+    fn default() -> Self {
+        Self::new(T::from_f64(0.9), T::from_f64(0.999), epsilon())
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for Adam<T> {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        self.update(parameters, lr, T::zero());
+    }
+}
+
+#[derive(Clone, Debug)]
+/// AdamW optimizer.
+/// 
+/// AdamW is `Adam` with decoupled weight decay: each value `w` is decayed by
+/// `lr * weight_decay * w` independently of the moment estimates.
+pub struct AdamW<T: Numeric> {
+    /// Underlying Adam optimizer.
+    pub adam: Adam<T>,
+
+    /// Weight decay coefficient.
+    pub weight_decay: T,
+}
+
+impl<T: Numeric> AdamW<T> {
+    /// Construct a new AdamW optimizer with the given decay rates, epsilon
+    /// and weight decay coefficient.
+    pub fn new(beta1: T, beta2: T, epsilon: T, weight_decay: T) -> Self {
+        Self {
+            adam: Adam::new(beta1, beta2, epsilon),
+            weight_decay,
+        }
+    }
+}
+
+impl<T: Numeric> Default for AdamW<T> {
+    /// Construct a new AdamW optimizer with `beta1 = 0.9`, `beta2 = 0.999`,
+    /// `epsilon = 1e-8` and `weight_decay = 0.01`.
+    fn default() -> Self {
+        Self {
+            adam: Adam::default(),
+            weight_decay: T::from_f64(0.01),
+        }
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for AdamW<T> {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        self.adam.update(parameters, lr, self.weight_decay);
+    }
+}
+
+#[test]
+fn test_adam_optimizer() {
+    // Minimize (w - 3)^2 from w = 0
+    let mut w = [0.0f64];
+    let mut g = [0.0f64];
+    let mut adam = Adam::default();
+
+    // First step moves each value by exactly `lr`, whatever the gradient scale
+    g[0] = 2.0 * (w[0] - 3.0);
+    adam.step(&mut [Parameter::new(&mut w, &mut g)], 0.1);
+    assert!((w[0] - 0.1).abs() < 1e-6);
+
+    for _ in 0..500 {
+        g[0] = 2.0 * (w[0] - 3.0);
+        adam.step(&mut [Parameter::new(&mut w, &mut g)], 0.1);
+    }
+    assert!((w[0] - 3.0).abs() < 1e-2);
+}
@@ -0,0 +1,100 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Nonlinear conjugate gradient minimizer.
+
+use crate::{
+    Numeric,
+    optim::Minimizer,
+    optimizer::linesearch::{
+        dot,
+        line_search,
+    },
+};
+
+#[derive(Clone, Copy, Debug)]
+/// Nonlinear conjugate gradient minimizer.
+/// 
+/// This minimizer uses the Polak-Ribière update `beta = g' . (g' - g) / (g . g)`,
+/// restarting along the steepest descent direction whenever `beta` is negative or
+/// the search direction is not a descent direction.  It is intended for full-batch
+/// training of small models.
+pub struct ConjugateGradient<T: Numeric> {
+    /// Maximum number of iterations.
+    pub iterations: usize,
+
+    /// Stop once the gradient norm falls below this value.
+    pub tolerance: T,
+}
+
+impl<T: Numeric> ConjugateGradient<T> {
+    /// Construct a new conjugate gradient minimizer.
+    pub fn new(iterations: usize, tolerance: T) -> Self {
+        Self {
+            iterations,
+            tolerance,
+        }
+    }
+}
+
+impl<T: Numeric> Default for ConjugateGradient<T> {
+    /// Construct a new conjugate gradient minimizer with `iterations = 100`
+    /// and `tolerance = 1e-6`.
+    fn default() -> Self {
+        Self::new(100, T::from_f64(1e-6))
+    }
+}
+
+impl<T: Numeric> Minimizer<T> for ConjugateGradient<T> {
+    fn minimize<F: FnMut(&[T]) -> (T, Vec<T>)>(&mut self, x: Vec<T>, mut f: F) -> Vec<T> {
+        let mut x = x;
+        let (mut value, mut gradient) = f(&x);
+
+        // Search direction, starting with steepest descent
+        let mut d: Vec<T> = gradient.iter().map(|g| -*g).collect();
+        let mut alpha = T::one() / dot(&gradient, &gradient).sqrt();
+
+        for _ in 0..self.iterations {
+            if dot(&gradient, &gradient).sqrt() < self.tolerance {
+                break;
+            }
+
+            // Restart if this is not a descent direction
+            if dot(&gradient, &d) >= T::zero() {
+                d = gradient.iter().map(|g| -*g).collect();
+            }
+
+            let step = match line_search(&mut f, &x, value, &gradient, &d, alpha) {
+                Some (step) => step,
+                None => break,
+            };
+
+            // Polak-Ribière update, restarting when negative
+            let gg = dot(&gradient, &gradient);
+            let mut beta = T::zero();
+            for (new, old) in step.gradient.iter().zip(gradient.iter()) {
+                beta = beta + *new * (*new - *old);
+            }
+            beta = beta / gg;
+            if beta < T::zero() {
+                beta = T::zero();
+            }
+
+            // Initial step for the next line search, assuming the same first-order change
+            let slope = dot(&gradient, &d);
+            for (d, g) in d.iter_mut().zip(step.gradient.iter()) {
+                *d = -*g + beta * *d;
+            }
+            alpha = step.alpha * slope / dot(&step.gradient, &d);
+            if alpha <= T::zero() {
+                alpha = T::one() / dot(&step.gradient, &step.gradient).sqrt();
+            }
+
+            x = step.x;
+            value = step.value;
+            gradient = step.gradient;
+        }
+
+        x
+    }
+}
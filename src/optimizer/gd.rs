@@ -0,0 +1,36 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Gradient descent optimizer.
+
+use crate::{
+    Numeric,
+    optim::{
+        Optimizer,
+        Parameter,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Plain gradient descent.
+/// 
+/// Each parameter `w` is updated as `w - lr * g`, where `g` is its gradient.
+/// Because layers average their gradients over each `Batch`, this is batch gradient descent.
+pub struct GradientDescent;
+
+impl GradientDescent {
+    /// Construct a new gradient descent optimizer.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for GradientDescent {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        for parameter in parameters.iter_mut() {
+            for i in parameter.indices() {
+                parameter.value[i] = parameter.value[i] - lr * parameter.gradient[i];
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Limited-memory BFGS minimizer.
+
+use std::collections::VecDeque;
+
+use crate::{
+    Numeric,
+    optim::Minimizer,
+    optimizer::linesearch::{
+        dot,
+        line_search,
+    },
+};
+
+#[derive(Clone, Copy, Debug)]
+/// Limited-memory BFGS minimizer.
+/// 
+/// L-BFGS approximates the inverse Hessian of the objective from the last `history`
+/// steps and gradient differences, and searches along the resulting quasi-Newton
+/// direction.  It is intended for full-batch training of small models.
+pub struct LBFGS<T: Numeric> {
+    /// Number of past steps used to approximate the inverse Hessian.
+    /// 
+    /// With `history = 0`, this searches along the scaled steepest-descent direction.
+    pub history: usize,
+
+    /// Maximum number of iterations.
+    pub iterations: usize,
+
+    /// Stop once the gradient norm falls below this value.
+    pub tolerance: T,
+}
+
+impl<T: Numeric> LBFGS<T> {
+    /// Construct a new L-BFGS minimizer.
+    pub fn new(history: usize, iterations: usize, tolerance: T) -> Self {
+        Self {
+            history,
+            iterations,
+            tolerance,
+        }
+    }
+}
+
+impl<T: Numeric> Default for LBFGS<T> {
+    /// Construct a new L-BFGS minimizer with `history = 10`, `iterations = 100`
+    /// and `tolerance = 1e-6`.
+    fn default() -> Self {
+        Self::new(10, 100, T::from_f64(1e-6))
+    }
+}
+
+impl<T: Numeric> Minimizer<T> for LBFGS<T> {
+    fn minimize<F: FnMut(&[T]) -> (T, Vec<T>)>(&mut self, x: Vec<T>, mut f: F) -> Vec<T> {
+        let mut x = x;
+        let (mut value, mut gradient) = f(&x);
+
+        // Past steps `s`, gradient differences `y` and `1 / (y . s)`
+        let mut history: VecDeque<(Vec<T>, Vec<T>, T)> = VecDeque::new();
+
+        for _ in 0..self.iterations {
+            if dot(&gradient, &gradient).sqrt() < self.tolerance {
+                break;
+            }
+
+            // Two-loop recursion to compute the quasi-Newton direction
+            let mut q = gradient.clone();
+            let mut a = vec![T::zero(); history.len()];
+            for (k, (s, y, rho)) in history.iter().enumerate().rev() {
+                a[k] = *rho * dot(s, &q);
+                for i in 0..q.len() {
+                    q[i] = q[i] - a[k] * y[i];
+                }
+            }
+            let gamma = match history.back() {
+                Some ((s, y, _)) => dot(s, y) / dot(y, y),
+                None => T::one() / dot(&gradient, &gradient).sqrt(),
+            };
+            for q in q.iter_mut() {
+                *q = gamma * *q;
+            }
+            for (k, (s, y, rho)) in history.iter().enumerate() {
+                let b = *rho * dot(y, &q);
+                for i in 0..q.len() {
+                    q[i] = q[i] + s[i] * (a[k] - b);
+                }
+            }
+            let d: Vec<T> = q.into_iter().map(|q| -q).collect();
+
+            let step = match line_search(&mut f, &x, value, &gradient, &d, T::one()) {
+                Some (step) => step,
+                None => break,
+            };
+
+            // Store curvature pair, skipping it if it is not positive
+            let s: Vec<T> = (0..x.len()).map(|i| step.x[i] - x[i]).collect();
+            let y: Vec<T> = (0..x.len()).map(|i| step.gradient[i] - gradient[i]).collect();
+            let sy = dot(&s, &y);
+            if sy > T::zero() && self.history > 0 {
+                while history.len() >= self.history {
+                    history.pop_front();
+                }
+                history.push_back((s, y, T::one() / sy));
+            }
+
+            x = step.x;
+            value = step.value;
+            gradient = step.gradient;
+        }
+
+        x
+    }
+}
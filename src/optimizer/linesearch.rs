@@ -0,0 +1,96 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Line search for full-batch minimizers.
+
+use crate::Numeric;
+
+/// Maximum number of step size reductions in a line search.
+const MAX_BACKTRACKS: usize = 40;
+
+/// Armijo sufficient decrease constant.
+const ARMIJO: f64 = 1e-4;
+
+/// Accepted step of a line search.
+pub struct Step<T: Numeric> {
+    /// Step size.
+    pub alpha: T,
+
+    /// New point.
+    pub x: Vec<T>,
+
+    /// Objective value at the new point.
+    pub value: T,
+
+    /// Objective gradient at the new point.
+    pub gradient: Vec<T>,
+}
+
+/// Dot product of two vectors.
+pub fn dot<T: Numeric>(a: &[T], b: &[T]) -> T {
+    let mut result = T::zero();
+    for i in 0..a.len() {
+        result = result + a[i] * b[i];
+    }
+    result
+}
+
+/// Search along direction `d` from `x` for a step satisfying the Armijo condition,
+/// starting from step size `alpha`.
+/// 
+/// Each trial fits a quadratic to the objective along `d` and tries its minimizer,
+/// so that quadratic objectives are minimized (nearly) exactly along `d`.
+/// 
+/// This function returns `None` if `d` is not a descent direction, or if no
+/// acceptable step is found.
+pub fn line_search<T: Numeric, F: FnMut(&[T]) -> (T, Vec<T>)>(f: &mut F, x: &[T], value: T, gradient: &[T], d: &[T], alpha: T) -> Option<Step<T>> {
+    let slope = dot(gradient, d);
+    if slope >= T::zero() {
+        return None;
+    }
+
+    let c1 = T::from_f64(ARMIJO);
+    let tenth = T::from_f64(0.1);
+    let half = T::from_f64(0.5);
+    let two = T::one() + T::one();
+
+    let trial = |f: &mut F, alpha: T| {
+        let x_new: Vec<T> = (0..x.len()).map(|i| x[i] + alpha * d[i]).collect();
+        let (value, gradient) = f(&x_new);
+        Step {
+            alpha,
+            x: x_new,
+            value,
+            gradient,
+        }
+    };
+
+    let mut alpha = alpha;
+    for _ in 0..MAX_BACKTRACKS {
+        let step = trial(f, alpha);
+
+        // Minimizer of the quadratic through `value`, `slope` and `step.value`
+        let curvature = two * (step.value - value - slope * alpha);
+        let alpha_q = -slope * alpha * alpha / curvature;
+
+        if step.value <= value + c1 * alpha * slope {
+            // Sufficient decrease: try the interpolated step as well, and keep the better
+            if curvature > T::zero() && alpha_q > T::zero() && alpha_q != alpha {
+                let interpolated = trial(f, alpha_q);
+                if interpolated.value < step.value {
+                    return Some (interpolated);
+                }
+            }
+            return Some (step);
+        }
+
+        // Insufficient decrease: shrink step, using the interpolated step if reasonable
+        alpha = if curvature > T::zero() && alpha_q > tenth * alpha && alpha_q < half * alpha {
+            alpha_q
+        } else {
+            half * alpha
+        };
+    }
+
+    None
+}
@@ -0,0 +1,175 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Optimizer abstraction.
+
+mod adagrad;
+mod adam;
+mod cg;
+mod gd;
+mod lbfgs;
+mod linesearch;
+mod rmsprop;
+mod sgd;
+
+use crate::Numeric;
+
+pub use adagrad::Adagrad;
+pub use adam::{
+    Adam,
+    AdamW,
+};
+pub use cg::ConjugateGradient;
+pub use gd::GradientDescent;
+pub use lbfgs::LBFGS;
+pub use rmsprop::RMSProp;
+pub use sgd::SGD;
+
+/// Trainable parameter of a network layer.
+/// 
+/// A `Parameter` borrows the values of a layer parameter (flattened into a slice)
+/// along with the gradient of the loss with respect to each value, as computed
+/// during the last backward pass.
+/// 
+/// A parameter is either dense, so that optimizers update all of its values, or sparse,
+/// so that optimizers update only the rows which may have nonzero gradients (e.g. the
+/// rows of an `Embedding` table looked up in the last forward pass).
+pub struct Parameter<'a, T: Numeric> {
+    /// Parameter values.
+    pub value: &'a mut [T],
+
+    /// Parameter gradients.
+    pub gradient: &'a mut [T],
+
+    /// Rows which may have nonzero gradients, along with the number of values in
+    /// each row, or `None` if this parameter is dense.
+    rows: Option<(&'a [usize], usize)>,
+}
+
+impl<'a, T: Numeric> Parameter<'a, T> {
+    /// Construct a new dense parameter.
+    pub fn new(value: &'a mut [T], gradient: &'a mut [T]) -> Self {
+        Self {
+            value,
+            gradient,
+            rows: None,
+        }
+    }
+
+    /// Construct a new sparse parameter, laid out as rows of `width` values, of which
+    /// only the given `rows` may have nonzero gradients.
+    pub fn sparse(value: &'a mut [T], gradient: &'a mut [T], rows: &'a [usize], width: usize) -> Self {
+        Self {
+            value,
+            gradient,
+            rows: Some ((rows, width)),
+        }
+    }
+
+    /// Iterate over the indices of the values which optimizers should update, i.e. every
+    /// index of a dense parameter, or the indices in the given rows of a sparse parameter.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + use<'a, T> {
+        let dense = self.rows.is_none().then_some(0..self.value.len());
+        let sparse = self.rows.map(|(rows, width)| rows.iter().flat_map(move |&row| row*width..(row+1)*width));
+        dense.into_iter().flatten().chain(sparse.into_iter().flatten())
+    }
+}
+
+/// Optimizer abstraction.
+/// 
+/// Layers compute and store the gradients of their parameters in `Layer::backward`.
+/// An `Optimizer` then applies those gradients in `Optimizer::step`.
+/// 
+/// NOTE parameters are passed to `Optimizer::step` in the same order on every call,
+/// so optimizers may associate internal state with each parameter by its position.
+pub trait Optimizer<T: Numeric> {
+    /// Update the given parameters using their gradients and learning rate `lr`.
+    /// 
+    /// Only the values in `Parameter::indices` are updated, so the values and internal
+    /// state of rows of a sparse parameter which were not touched are left unchanged.
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T);
+}
+
+/// Full-batch minimizer abstraction.
+/// 
+/// Unlike an `Optimizer`, which takes one step per `Batch`, a `Minimizer` drives the
+/// whole minimization itself, evaluating the objective as often as it needs.  The
+/// objective `f` maps a flattened parameter vector to its value and gradient; see
+/// `Architecture::minimize`.
+pub trait Minimizer<T: Numeric> {
+    /// Minimize the objective `f` starting from `x`, returning the final parameters.
+    fn minimize<F: FnMut(&[T]) -> (T, Vec<T>)>(&mut self, x: Vec<T>, f: F) -> Vec<T>;
+}
+
+/// Optimization algorithm, as selected in `Hyperparameters`.
+#[derive(Clone, Copy, Debug)]
+pub enum Algorithm<T: Numeric> {
+    /// Batch gradient descent.  See `GradientDescent`.
+    GradientDescent,
+
+    /// Gradient descent with (optionally Nesterov) momentum.  See `SGD`.
+    SGD {
+        momentum: T,
+        nesterov: bool,
+    },
+
+    /// RMSProp.  See `RMSProp`.
+    RMSProp {
+        rho: T,
+        epsilon: T,
+    },
+
+    /// Adagrad.  See `Adagrad`.
+    Adagrad {
+        epsilon: T,
+    },
+
+    /// Adam.  See `Adam`.
+    Adam {
+        beta1: T,
+        beta2: T,
+        epsilon: T,
+    },
+
+    /// Adam with decoupled weight decay.  See `AdamW`.
+    AdamW {
+        beta1: T,
+        beta2: T,
+        epsilon: T,
+        weight_decay: T,
+    },
+}
+
+impl<T: Numeric> Algorithm<T> {
+    /// Construct a new optimizer implementing this algorithm.
+    pub fn optimizer<'a>(&self) -> Box<dyn Optimizer<T> + 'a> where T: 'a {
+        match *self {
+            Self::GradientDescent => Box::new(GradientDescent::new()),
+            Self::SGD {momentum, nesterov} => Box::new(SGD::new(momentum, nesterov)),
+            Self::RMSProp {rho, epsilon} => Box::new(RMSProp::new(rho, epsilon)),
+            Self::Adagrad {epsilon} => Box::new(Adagrad::new(epsilon)),
+            Self::Adam {beta1, beta2, epsilon} => Box::new(Adam::new(beta1, beta2, epsilon)),
+            Self::AdamW {beta1, beta2, epsilon, weight_decay} => Box::new(AdamW::new(beta1, beta2, epsilon, weight_decay)),
+        }
+    }
+}
+
+/// Allocate one zeroed state buffer for each parameter not yet seen by an optimizer.
+fn allocate<T: Numeric>(buffers: &mut Vec<Vec<T>>, parameters: &[Parameter<'_, T>]) {
+    for parameter in parameters.iter().skip(buffers.len()) {
+        buffers.push(vec![T::zero(); parameter.value.len()]);
+    }
+}
+
+/// Default epsilon.
+/// 
+/// NOTE low-precision types such as `x16` round `1e-8` to zero, so we fall back
+/// to the smallest representable value to avoid dividing by zero.
+fn epsilon<T: Numeric>() -> T {
+    let epsilon = T::from_f64(1e-8);
+    if epsilon > T::zero() {
+        epsilon
+    } else {
+        T::tiny()
+    }
+}
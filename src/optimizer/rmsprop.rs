@@ -0,0 +1,66 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! RMSProp optimizer.
+
+use crate::{
+    Numeric,
+    optim::{
+        Optimizer,
+        Parameter,
+    },
+    optimizer::{
+        allocate,
+        epsilon,
+    },
+};
+
+#[derive(Clone, Debug)]
+/// RMSProp optimizer.
+/// 
+/// Each parameter keeps a moving average `s = rho * s + (1 - rho) * g^2` of its
+/// squared gradient, and is updated as `w - lr * g / (sqrt(s) + epsilon)`.
+pub struct RMSProp<T: Numeric> {
+    /// Decay rate of the squared gradient average.
+    pub rho: T,
+
+    /// Small value added to the denominator for numerical stability.
+    pub epsilon: T,
+
+    /// Squared gradient average of each parameter.
+    square_avg: Vec<Vec<T>>,
+}
+
+impl<T: Numeric> RMSProp<T> {
+    /// Construct a new RMSProp optimizer with the given decay rate and epsilon.
+    pub fn new(rho: T, epsilon: T) -> Self {
+        Self {
+            rho,
+            epsilon,
+            square_avg: Vec::new(),
+        }
+    }
+}
+
+impl<T: Numeric> Default for RMSProp<T> {
+    /// Construct a new RMSProp optimizer with `rho = 0.99` and `epsilon = 1e-8`.
+    fn default() -> Self {
+        Self::new(T::from_f64(0.99), epsilon())
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for RMSProp<T> {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        allocate(&mut self.square_avg, parameters);
+
+        for (p, parameter) in parameters.iter_mut().enumerate() {
+            let s = &mut self.square_avg[p];
+
+            for i in parameter.indices() {
+                let g = parameter.gradient[i];
+                s[i] = self.rho * s[i] + (T::one() - self.rho) * g * g;
+                parameter.value[i] = parameter.value[i] - lr * g / (s[i].sqrt() + self.epsilon);
+            }
+        }
+    }
+}
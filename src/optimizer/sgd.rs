@@ -0,0 +1,70 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Stochastic gradient descent with momentum.
+
+use crate::{
+    Numeric,
+    optim::{
+        Optimizer,
+        Parameter,
+    },
+    optimizer::allocate,
+};
+
+#[derive(Clone, Debug)]
+/// Gradient descent with momentum.
+/// 
+/// Each parameter keeps a velocity `v`, updated as `v = momentum * v + g`.  Values
+/// are then updated as `w - lr * v`, or as `w - lr * (g + momentum * v)` when using
+/// Nesterov momentum.  With `momentum = 0`, this is plain gradient descent.
+pub struct SGD<T: Numeric> {
+    /// Momentum coefficient.
+    pub momentum: T,
+
+    /// Use Nesterov momentum?
+    pub nesterov: bool,
+
+    /// Velocity of each parameter.
+    velocity: Vec<Vec<T>>,
+}
+
+impl<T: Numeric> SGD<T> {
+    /// Construct a new optimizer with the given momentum.
+    pub fn new(momentum: T, nesterov: bool) -> Self {
+        Self {
+            momentum,
+            nesterov,
+            velocity: Vec::new(),
+        }
+    }
+}
+
+impl<T: Numeric> Default for SGD<T> {
+    /// Construct a new optimizer with `momentum = 0.9` and no Nesterov momentum.
+    fn default() -> Self {
+        Self::new(T::from_f64(0.9), false)
+    }
+}
+
+impl<T: Numeric> Optimizer<T> for SGD<T> {
+    fn step(&mut self, parameters: &mut [Parameter<'_, T>], lr: T) {
+        allocate(&mut self.velocity, parameters);
+
+        for (p, parameter) in parameters.iter_mut().enumerate() {
+            let v = &mut self.velocity[p];
+
+            for i in parameter.indices() {
+                let g = parameter.gradient[i];
+                v[i] = self.momentum * v[i] + g;
+
+                let update = if self.nesterov {
+                    g + self.momentum * v[i]
+                } else {
+                    v[i]
+                };
+                parameter.value[i] = parameter.value[i] - lr * update;
+            }
+        }
+    }
+}
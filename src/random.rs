@@ -0,0 +1,56 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//!
+//! Seedable random number generation.
+
+use std::cell::RefCell;
+
+use rand::{
+    Rng,
+    rngs::StdRng,
+    SeedableRng,
+    distr::{
+        Distribution,
+        StandardUniform,
+    },
+};
+
+thread_local! {
+    /// Random number generator of this thread, seeded from the operating system
+    /// until `seed` is called.
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_os_rng());
+}
+
+/// Seed the random number generator of this thread.
+/// 
+/// All randomness in this crate (parameter initialization, dataset shuffling and
+/// dropout masks) is drawn from this generator, so seeding it makes runs reproducible.
+pub fn seed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+/// Run `f` with the random number generator of this thread.
+pub(crate) fn with_rng<R, F: FnOnce(&mut StdRng) -> R>(f: F) -> R {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Draw a value from the standard uniform distribution of `U`, e.g. `[0, 1)` for floats.
+pub(crate) fn uniform<U>() -> U
+where
+    StandardUniform: Distribution<U>,
+{
+    with_rng(|rng| rng.random())
+}
+
+#[test]
+fn test_seed() {
+    seed(42);
+    let first: Vec<f64> = (0..4).map(|_| uniform()).collect();
+    seed(42);
+    let second: Vec<f64> = (0..4).map(|_| uniform()).collect();
+    assert_eq!(first, second);
+
+    seed(43);
+    let third: Vec<f64> = (0..4).map(|_| uniform()).collect();
+    assert_ne!(first, third);
+}
@@ -0,0 +1,202 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Learning rate schedule abstraction.
+
+use std::f64::consts::PI;
+
+use crate::Numeric;
+
+/// Learning rate schedule, as selected in `Hyperparameters`.
+/// 
+/// A schedule scales the base learning rate `Hyperparameters::lr` at the
+/// start of each training epoch.  Epochs are counted from zero.
+#[derive(Clone, Copy, Debug)]
+pub enum LrSchedule<T: Numeric> {
+    /// Constant learning rate.
+    Constant,
+
+    /// Multiply the learning rate by `gamma` every `step_size` epochs, which must be
+    /// positive.
+    Step {
+        step_size: usize,
+        gamma: T,
+    },
+
+    /// Multiply the learning rate by `gamma` every epoch.
+    Exponential {
+        gamma: T,
+    },
+
+    /// Cosine annealing with warm restarts.
+    /// 
+    /// The learning rate decays from its base value to `min_lr` along a half cosine
+    /// over `period` epochs, which must be positive, then restarts.  Each period is
+    /// `multiplier` times as long as the last.
+    CosineAnnealing {
+        period: usize,
+        multiplier: usize,
+        min_lr: T,
+    },
+
+    /// Increase the learning rate linearly from `lr / warmup` to `lr` over the
+    /// first `warmup` epochs, then hold it constant.
+    LinearWarmup {
+        warmup: usize,
+    },
+
+    /// Multiply the learning rate by `factor` (down to `min_lr`) whenever the epoch
+    /// loss has not improved on the best loss so far by a relative `threshold` for
+    /// more than `patience` epochs.
+    ReduceOnPlateau {
+        factor: T,
+        patience: usize,
+        threshold: T,
+        min_lr: T,
+    },
+}
+
+/// Learning rate scheduler.
+/// 
+/// This tracks the state of an `LrSchedule` over the course of training.
+pub struct Scheduler<T: Numeric> {
+    /// Schedule to follow.
+    schedule: LrSchedule<T>,
+
+    /// Base learning rate.
+    lr: T,
+
+    /// Current learning rate for `LrSchedule::ReduceOnPlateau`.
+    current: T,
+
+    /// Best epoch loss so far.
+    best: Option<T>,
+
+    /// Number of epochs since the best epoch loss.
+    bad_epochs: usize,
+}
+
+impl<T: Numeric> Scheduler<T> {
+    /// Construct a new scheduler for the given schedule and base learning rate.
+    pub fn new(schedule: LrSchedule<T>, lr: T) -> Self {
+        match schedule {
+            LrSchedule::Step {step_size, ..} => assert!(step_size > 0, "step_size must be positive"),
+            LrSchedule::CosineAnnealing {period, ..} => assert!(period > 0, "period must be positive"),
+            _ => {},
+        }
+
+        Self {
+            schedule,
+            lr,
+            current: lr,
+            best: None,
+            bad_epochs: 0,
+        }
+    }
+
+    /// Get the learning rate for the given epoch.
+    pub fn lr(&self, epoch: usize) -> T {
+        match self.schedule {
+            LrSchedule::Constant => self.lr,
+            LrSchedule::Step {step_size, gamma} => self.lr * power(gamma, epoch / step_size),
+            LrSchedule::Exponential {gamma} => self.lr * power(gamma, epoch),
+            LrSchedule::CosineAnnealing {period, multiplier, min_lr} => {
+                // Find position within the current period
+                let mut t = epoch;
+                let mut period = period;
+                while t >= period {
+                    t -= period;
+                    period *= multiplier.max(1);
+                }
+
+                let factor = 0.5 * (1.0 + (PI * t as f64 / period as f64).cos());
+                min_lr + (self.lr - min_lr) * T::from_f64(factor)
+            },
+            LrSchedule::LinearWarmup {warmup} => if epoch < warmup {
+                self.lr * T::from_f64((epoch + 1) as f64 / warmup as f64)
+            } else {
+                self.lr
+            },
+            LrSchedule::ReduceOnPlateau {..} => self.current,
+        }
+    }
+
+    /// Record the loss of the last epoch.
+    pub fn observe(&mut self, loss: T) {
+        if let LrSchedule::ReduceOnPlateau {factor, patience, threshold, min_lr} = self.schedule {
+            match self.best {
+                Some (best) if loss >= best - threshold * best => {
+                    self.bad_epochs += 1;
+                    if self.bad_epochs > patience {
+                        self.current = self.current * factor;
+                        if self.current < min_lr {
+                            self.current = min_lr;
+                        }
+                        self.bad_epochs = 0;
+                    }
+                },
+                _ => {
+                    self.best = Some (loss);
+                    self.bad_epochs = 0;
+                },
+            }
+        }
+    }
+}
+
+/// Raise `x` to a nonnegative integer power.
+fn power<T: Numeric>(x: T, n: usize) -> T {
+    let mut result = T::one();
+    for _ in 0..n {
+        result = result * x;
+    }
+    result
+}
+
+#[test]
+fn test_lr_schedules() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+    let step = Scheduler::new(LrSchedule::Step {step_size: 2, gamma: 0.5}, 1.0);
+    assert!(close(step.lr(1), 1.0));
+    assert!(close(step.lr(2), 0.5));
+    assert!(close(step.lr(5), 0.25));
+
+    let exponential = Scheduler::new(LrSchedule::Exponential {gamma: 0.5}, 1.0);
+    assert!(close(exponential.lr(3), 0.125));
+
+    let cosine = Scheduler::new(LrSchedule::CosineAnnealing {period: 4, multiplier: 2, min_lr: 0.0}, 1.0);
+    assert!(close(cosine.lr(0), 1.0));
+    assert!(close(cosine.lr(2), 0.5));
+    assert!(close(cosine.lr(4), 1.0));
+    assert!(close(cosine.lr(8), 0.5));
+    assert!(close(cosine.lr(12), 1.0));
+
+    let warmup = Scheduler::new(LrSchedule::LinearWarmup {warmup: 4}, 1.0);
+    assert!(close(warmup.lr(0), 0.25));
+    assert!(close(warmup.lr(3), 1.0));
+    assert!(close(warmup.lr(10), 1.0));
+
+    let mut plateau = Scheduler::new(LrSchedule::ReduceOnPlateau {factor: 0.1, patience: 1, threshold: 0.0, min_lr: 0.0}, 1.0);
+    for loss in [3.0, 2.0, 2.0] {
+        plateau.observe(loss);
+    }
+    assert!(close(plateau.lr(3), 1.0));
+    plateau.observe(2.0);
+    assert!(close(plateau.lr(4), 0.1));
+    plateau.observe(1.0);
+    plateau.observe(1.0);
+    assert!(close(plateau.lr(6), 0.1));
+}
+
+#[test]
+#[should_panic(expected = "step_size must be positive")]
+fn test_lr_schedule_zero_step_size() {
+    Scheduler::new(LrSchedule::Step {step_size: 0, gamma: 0.5}, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "period must be positive")]
+fn test_lr_schedule_zero_period() {
+    Scheduler::new(LrSchedule::CosineAnnealing {period: 0, multiplier: 2, min_lr: 0.0}, 1.0);
+}
@@ -0,0 +1,464 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Dynamically shaped tensor implementation.
+
+use std::{
+    ops::{
+        Add,
+        Div,
+        Index,
+        Mul,
+        Sub,
+    },
+    sync::Arc,
+};
+
+use crate::{
+    Numeric,
+    tensor::Tensor,
+};
+
+#[derive(Clone, Debug)]
+/// Tensor with a shape known only at runtime.
+/// 
+/// A `DynTensor` is a strided view into shared storage, so `DynTensor::reshape` (of a
+/// contiguous tensor), `DynTensor::transpose`, `DynTensor::slice` and
+/// `DynTensor::broadcast_to` never copy values.  Elementwise operators broadcast their
+/// operands as in NumPy, and panic if their shapes are incompatible.
+pub struct DynTensor<T: Numeric> {
+    /// Shared storage.
+    data: Arc<[T]>,
+
+    /// Size of each axis.
+    shape: Vec<usize>,
+
+    /// Storage step along each axis.
+    strides: Vec<usize>,
+
+    /// Storage index of the first value.
+    offset: usize,
+}
+
+impl<T: Numeric> DynTensor<T> {
+    /// Construct a new tensor from values in row-major order and a shape.
+    /// 
+    /// This function returns `None` if the number of values does not match the shape.
+    pub fn new(data: Vec<T>, shape: &[usize]) -> Option<Self> {
+        if data.len() != shape.iter().product() {
+            return None;
+        }
+
+        Some (Self {
+            data: data.into(),
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+            offset: 0,
+        })
+    }
+
+    /// Construct a new tensor of zeros with the given shape.
+    pub fn zero(shape: &[usize]) -> Self {
+        let len = shape.iter().product();
+        Self {
+            data: vec![T::zero(); len].into(),
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+            offset: 0,
+        }
+    }
+
+    /// Construct a new tensor with no axes, holding a single value.
+    pub fn scalar(value: T) -> Self {
+        Self {
+            data: vec![value].into(),
+            shape: Vec::new(),
+            strides: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// Get the size of each axis.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Get the storage step along each axis.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Get the number of axes.
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Get the number of values.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Does this tensor hold no values?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Are the values of this tensor laid out contiguously in row-major order?
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == contiguous_strides(&self.shape)
+    }
+
+    /// Get the value at the given multi-index, if it is in bounds.
+    pub fn get(&self, index: &[usize]) -> Option<T> {
+        if index.len() != self.ndim() || index.iter().zip(&self.shape).any(|(i, n)| i >= n) {
+            return None;
+        }
+        Some (self.data[self.position(index)])
+    }
+
+    /// Set the value at the given multi-index, copying storage if it is shared.
+    /// 
+    /// This function returns `None` if the index is out of bounds.
+    pub fn set(&mut self, index: &[usize], value: T) -> Option<()> {
+        self.get(index)?;
+
+        // Broadcast or shared views must own their values before writing
+        if !self.is_contiguous() || self.offset != 0 || self.data.len() != self.len() {
+            *self = self.to_contiguous();
+        }
+        let position = self.position(index);
+        Arc::make_mut(&mut self.data)[position] = value;
+        Some (())
+    }
+
+    /// Get all values in row-major order.
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.len());
+        for index in Indices::new(&self.shape) {
+            values.push(self.data[self.position(&index)]);
+        }
+        values
+    }
+
+    /// Copy this tensor into new contiguous storage.
+    pub fn to_contiguous(&self) -> Self {
+        Self {
+            data: self.to_vec().into(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+            offset: 0,
+        }
+    }
+
+    /// Reshape this tensor, copying values only if it is not contiguous.
+    /// 
+    /// This function returns `None` if the number of values does not match the new shape.
+    pub fn reshape(&self, shape: &[usize]) -> Option<Self> {
+        if self.len() != shape.iter().product() {
+            return None;
+        }
+
+        let source = if self.is_contiguous() {
+            self.clone()
+        } else {
+            self.to_contiguous()
+        };
+
+        Some (Self {
+            data: source.data,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+            offset: source.offset,
+        })
+    }
+
+    /// Reverse the axes of this tensor.
+    pub fn transpose(&self) -> Self {
+        let axes: Vec<usize> = (0..self.ndim()).rev().collect();
+        self.permute(&axes).unwrap()
+    }
+
+    /// Reorder the axes of this tensor, so that axis `i` of the result is axis `axes[i]`
+    /// of this tensor.
+    /// 
+    /// This function returns `None` if `axes` is not a permutation of this tensor's axes.
+    pub fn permute(&self, axes: &[usize]) -> Option<Self> {
+        let mut seen = vec![false; self.ndim()];
+        for &axis in axes {
+            if axis >= self.ndim() || seen[axis] {
+                return None;
+            }
+            seen[axis] = true;
+        }
+        if axes.len() != self.ndim() {
+            return None;
+        }
+
+        Some (Self {
+            data: self.data.clone(),
+            shape: axes.iter().map(|&a| self.shape[a]).collect(),
+            strides: axes.iter().map(|&a| self.strides[a]).collect(),
+            offset: self.offset,
+        })
+    }
+
+    /// Restrict the given axis to the indices `start..end`.
+    /// 
+    /// This function returns `None` if the axis or range is out of bounds.
+    pub fn slice(&self, axis: usize, start: usize, end: usize) -> Option<Self> {
+        if axis >= self.ndim() || start > end || end > self.shape[axis] {
+            return None;
+        }
+
+        let mut shape = self.shape.clone();
+        shape[axis] = end - start;
+        Some (Self {
+            data: self.data.clone(),
+            shape,
+            strides: self.strides.clone(),
+            offset: self.offset + start * self.strides[axis],
+        })
+    }
+
+    /// Broadcast this tensor to the given shape, as in NumPy.
+    /// 
+    /// This function returns `None` if this tensor cannot be broadcast to `shape`.
+    pub fn broadcast_to(&self, shape: &[usize]) -> Option<Self> {
+        if shape.len() < self.ndim() {
+            return None;
+        }
+
+        // Align axes from the right, repeating axes of size one with stride zero
+        let extra = shape.len() - self.ndim();
+        let mut strides = vec![0; shape.len()];
+        for i in 0..self.ndim() {
+            if self.shape[i] == shape[extra + i] {
+                strides[extra + i] = self.strides[i];
+            } else if self.shape[i] != 1 {
+                return None;
+            }
+        }
+
+        Some (Self {
+            data: self.data.clone(),
+            shape: shape.to_vec(),
+            strides,
+            offset: self.offset,
+        })
+    }
+
+    /// Apply `f` to each value.
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        Self {
+            data: self.to_vec().into_iter().map(f).collect::<Vec<T>>().into(),
+            shape: self.shape.clone(),
+            strides: contiguous_strides(&self.shape),
+            offset: 0,
+        }
+    }
+
+    /// Apply `f` to each pair of values of this tensor and `other`, broadcasting both
+    /// to a common shape.
+    /// 
+    /// This function returns `None` if the shapes cannot be broadcast together.
+    pub fn zip_map<F: Fn(T, T) -> T>(&self, other: &Self, f: F) -> Option<Self> {
+        let shape = broadcast_shape(&self.shape, &other.shape)?;
+        let a = self.broadcast_to(&shape)?;
+        let b = other.broadcast_to(&shape)?;
+
+        let mut values = Vec::with_capacity(shape.iter().product());
+        for index in Indices::new(&shape) {
+            values.push(f(a.data[a.position(&index)], b.data[b.position(&index)]));
+        }
+
+        Self::new(values, &shape)
+    }
+
+    /// Convert this tensor to a `Tensor`, flattening it in row-major order.
+    /// 
+    /// This function returns `None` if this tensor does not hold exactly `N` values.
+    pub fn to_tensor<const N: usize>(&self) -> Option<Tensor<T, N>> {
+        if self.len() != N {
+            return None;
+        }
+        let values = self.to_vec();
+        Some (Tensor::from_fn(|i| values[i]))
+    }
+
+    /// Storage index of the given multi-index.
+    fn position(&self, index: &[usize]) -> usize {
+        let mut position = self.offset;
+        for (i, stride) in index.iter().zip(self.strides.iter()) {
+            position += i * stride;
+        }
+        position
+    }
+}
+
+impl<T: Numeric, const N: usize> From<Tensor<T, N>> for DynTensor<T> {
+    fn from(tensor: Tensor<T, N>) -> Self {
+        Self::new(tensor.as_array().to_vec(), &[N]).unwrap()
+    }
+}
+
+impl<T: Numeric> PartialEq for DynTensor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.to_vec() == other.to_vec()
+    }
+}
+
+impl<T: Numeric> Index<&[usize]> for DynTensor<T> {
+    type Output = T;
+
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        assert!(self.get(index).is_some(), "index {:?} out of bounds for shape {:?}", index, self.shape);
+        &self.data[self.position(index)]
+    }
+}
+
+/// Implement a broadcasting elementwise operator for `DynTensor`s.
+macro_rules! elementwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T: Numeric> $trait<&DynTensor<T>> for &DynTensor<T> {
+            type Output = DynTensor<T>;
+
+            fn $method(self, other: &DynTensor<T>) -> DynTensor<T> {
+                match self.zip_map(other, |a, b| a $op b) {
+                    Some (result) => result,
+                    None => panic!("cannot broadcast shapes {:?} and {:?}", self.shape, other.shape),
+                }
+            }
+        }
+
+        impl<T: Numeric> $trait for DynTensor<T> {
+            type Output = DynTensor<T>;
+
+            fn $method(self, other: DynTensor<T>) -> DynTensor<T> {
+                &self $op &other
+            }
+        }
+    };
+}
+
+elementwise!(Add, add, +);
+elementwise!(Sub, sub, -);
+elementwise!(Mul, mul, *);
+elementwise!(Div, div, /);
+
+/// Row-major strides of a contiguous tensor with the given shape.
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+/// Common shape of two broadcast shapes, if any.
+fn broadcast_shape(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let ndim = a.len().max(b.len());
+    let mut shape = vec![0; ndim];
+    for i in 0..ndim {
+        // Align axes from the right, treating missing axes as size one
+        let m = if i + a.len() >= ndim { a[i + a.len() - ndim] } else { 1 };
+        let n = if i + b.len() >= ndim { b[i + b.len() - ndim] } else { 1 };
+        shape[i] = if m == n || n == 1 {
+            m
+        } else if m == 1 {
+            n
+        } else {
+            return None;
+        };
+    }
+    Some (shape)
+}
+
+/// Iterator over all multi-indices of a shape in row-major order.
+struct Indices {
+    shape: Vec<usize>,
+    next: Option<Vec<usize>>,
+}
+
+impl Indices {
+    fn new(shape: &[usize]) -> Self {
+        let next = if shape.contains(&0) {
+            None
+        } else {
+            Some (vec![0; shape.len()])
+        };
+        Self {
+            shape: shape.to_vec(),
+            next,
+        }
+    }
+}
+
+impl Iterator for Indices {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.take()?;
+
+        // Increment the last axis, carrying into earlier axes
+        let mut next = current.clone();
+        for i in (0..next.len()).rev() {
+            next[i] += 1;
+            if next[i] < self.shape[i] {
+                self.next = Some (next);
+                break;
+            }
+            next[i] = 0;
+        }
+
+        Some (current)
+    }
+}
+
+#[test]
+fn test_dyn_tensor_views() {
+    let a = DynTensor::new((0..6).map(|x| x as f64).collect(), &[2, 3]).unwrap();
+    assert_eq!(a[&[1, 2][..]], 5.0);
+    assert!(DynTensor::new(vec![0.0f64; 5], &[2, 3]).is_none());
+
+    // Transpose shares storage
+    let t = a.transpose();
+    assert_eq!(t.shape(), &[3, 2]);
+    assert_eq!(t.to_vec(), vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+    assert!(!t.is_contiguous());
+
+    // Reshaping a non-contiguous view copies it
+    let r = t.reshape(&[6]).unwrap();
+    assert_eq!(r.to_vec(), vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+    assert!(a.reshape(&[4]).is_none());
+
+    // Slice columns 1..3
+    let s = a.slice(1, 1, 3).unwrap();
+    assert_eq!(s.shape(), &[2, 2]);
+    assert_eq!(s.to_vec(), vec![1.0, 2.0, 4.0, 5.0]);
+
+    // Writing to a view does not affect the original
+    let mut w = s.clone();
+    w.set(&[0, 0], 10.0).unwrap();
+    assert_eq!(w.to_vec(), vec![10.0, 2.0, 4.0, 5.0]);
+    assert_eq!(s.to_vec(), vec![1.0, 2.0, 4.0, 5.0]);
+}
+
+#[test]
+fn test_dyn_tensor_broadcasting() {
+    let a = DynTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+    let row = DynTensor::new(vec![10.0, 20.0, 30.0], &[3]).unwrap();
+    let column = DynTensor::new(vec![1.0, 2.0], &[2, 1]).unwrap();
+
+    assert_eq!((&a + &row).to_vec(), vec![11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+    assert_eq!((&a * &column).to_vec(), vec![1.0, 2.0, 3.0, 8.0, 10.0, 12.0]);
+    assert_eq!((&a - &DynTensor::scalar(1.0)).to_vec(), vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    // Outer product by broadcasting
+    let outer = &column / &row.reshape(&[1, 3]).unwrap();
+    assert_eq!(outer.shape(), &[2, 3]);
+    assert_eq!(outer.to_vec(), vec![0.1, 0.05, 1.0 / 30.0, 0.2, 0.1, 2.0 / 30.0]);
+
+    // Incompatible shapes
+    let other = DynTensor::new(vec![1.0, 2.0], &[2]).unwrap();
+    assert!(a.zip_map(&other, |a, b| a + b).is_none());
+}
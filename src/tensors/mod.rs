@@ -0,0 +1,123 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Tensor implementation.
+
+mod dynamic;
+mod ops;
+mod shaped;
+
+use std::{
+    ops::{
+        Index,
+        IndexMut,
+    },
+    sync::Arc,
+};
+
+use crate::Numeric;
+
+pub use dynamic::DynTensor;
+pub use shaped::{
+    Tensor2,
+    Tensor3,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+/// Tensor of `N` values of type `T`.
+/// 
+/// Values are stored on the heap and shared between clones, so cloning a `Tensor`
+/// (or a `Batch`) never copies its values.  Values are copied on the first write to
+/// a shared `Tensor`, so clones behave as independent tensors.
+pub struct Tensor<T: Numeric, const N: usize> (Arc<[T; N]>);
+
+impl<T: Numeric, const N: usize> Tensor<T, N> {
+    /// Construct a new tensor from an array of values.
+    pub fn new(values: [T; N]) -> Self {
+        Self (Arc::new(values))
+    }
+
+    /// Construct a new tensor of zeros.
+    pub fn zero() -> Self {
+        Self::from_fn(|_| T::zero())
+    }
+
+    /// Construct a new tensor, computing the value at each index with `f`.
+    /// 
+    /// NOTE this builds the tensor directly on the heap, unlike `Tensor::new`.
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        let values: Box<[T]> = (0..N).map(f).collect();
+        let values: Box<[T; N]> = match values.try_into() {
+            Ok (values) => values,
+            Err (_) => unreachable!(),
+        };
+        Self (Arc::from(values))
+    }
+
+    /// Get the values of this tensor.
+    pub fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Get the values of this tensor mutably, copying them if they are shared.
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<T: Numeric, const N: usize> From<[T; N]> for Tensor<T, N> {
+    fn from(values: [T; N]) -> Self {
+        Self::new(values)
+    }
+}
+
+impl<T: Numeric, const N: usize> Index<usize> for Tensor<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T: Numeric, const N: usize> IndexMut<usize> for Tensor<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+/// Batch of `B` `Tensor`s.
+pub struct Batch<const B: usize, T: Numeric, const N: usize> (pub [Tensor<T, N>; B]);
+
+impl<const B: usize, T: Numeric, const N: usize> Batch<B, T, N> {
+    pub fn zero() -> Self {
+        Self (std::array::from_fn(|_| Tensor::<T, N>::zero()))
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize> Index<usize> for Batch<B, T, N> {
+    type Output = Tensor<T, N>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize> IndexMut<usize> for Batch<B, T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+#[test]
+fn test_tensor_copy_on_write() {
+    let tensor = Tensor::<f64, 3>::new([1.0, 2.0, 3.0]);
+    let mut copy = tensor.clone();
+    assert!(Arc::ptr_eq(&tensor.0, &copy.0));
+
+    // Writing to a shared tensor copies its values
+    copy[0] = 4.0;
+    assert!(!Arc::ptr_eq(&tensor.0, &copy.0));
+    assert_eq!(tensor, Tensor::new([1.0, 2.0, 3.0]));
+    assert_eq!(copy, Tensor::new([4.0, 2.0, 3.0]));
+}
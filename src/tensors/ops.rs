@@ -0,0 +1,328 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Arithmetic, reduction and linear-algebra operators on tensors.
+
+use std::ops::{
+    Add,
+    AddAssign,
+    Div,
+    Mul,
+    Neg,
+    Sub,
+    SubAssign,
+};
+
+use crate::{
+    Numeric,
+    tensor::{
+        Batch,
+        Tensor,
+    },
+};
+
+impl<T: Numeric, const N: usize> Tensor<T, N> {
+    /// Iterate over the values of this tensor.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_array().iter()
+    }
+
+    /// Iterate mutably over the values of this tensor, copying them if they are shared.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_array().iter_mut()
+    }
+
+    /// Apply `f` to each value.
+    pub fn map<F: FnMut(T) -> T>(&self, mut f: F) -> Self {
+        let values = self.as_array();
+        Self::from_fn(|i| f(values[i]))
+    }
+
+    /// Apply `f` to each pair of values of this tensor and `other`.
+    pub fn zip_map<F: FnMut(T, T) -> T>(&self, other: &Self, mut f: F) -> Self {
+        let (a, b) = (self.as_array(), other.as_array());
+        Self::from_fn(|i| f(a[i], b[i]))
+    }
+
+    /// Sum of all values.
+    pub fn sum(&self) -> T {
+        self.iter().fold(T::zero(), |sum, &x| sum + x)
+    }
+
+    /// Mean of all values.
+    pub fn mean(&self) -> T {
+        self.sum() / T::from_f64(N as f64)
+    }
+
+    /// Largest value.
+    /// 
+    /// NOTE this fails to compile if `N == 0`.
+    pub fn max(&self) -> T {
+        self[self.argmax()]
+    }
+
+    /// Index of the largest value, or the first such index if there are several.
+    /// 
+    /// NOTE this fails to compile if `N == 0`.
+    pub fn argmax(&self) -> usize {
+        const { assert!(N > 0, "an empty tensor has no largest value") };
+
+        let values = self.as_array();
+        let mut argmax = 0;
+        for i in 1..N {
+            if values[i] > values[argmax] {
+                argmax = i;
+            }
+        }
+        argmax
+    }
+
+    /// Dot product with `other`.
+    pub fn dot(&self, other: &Self) -> T {
+        let (a, b) = (self.as_array(), other.as_array());
+        let mut dot = T::zero();
+        for i in 0..N {
+            dot = dot + a[i] * b[i];
+        }
+        dot
+    }
+}
+
+impl<'a, T: Numeric, const N: usize> IntoIterator for &'a Tensor<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize> Batch<B, T, N> {
+    /// Iterate over the tensors of this batch.
+    pub fn iter(&self) -> std::slice::Iter<'_, Tensor<T, N>> {
+        self.0.iter()
+    }
+
+    /// Iterate mutably over the tensors of this batch.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Tensor<T, N>> {
+        self.0.iter_mut()
+    }
+
+    /// Apply `f` to each value.
+    pub fn map<F: FnMut(T) -> T>(&self, mut f: F) -> Self {
+        Self (std::array::from_fn(|b| self[b].map(&mut f)))
+    }
+
+    /// Apply `f` to each pair of values of this batch and `other`.
+    pub fn zip_map<F: FnMut(T, T) -> T>(&self, other: &Self, mut f: F) -> Self {
+        Self (std::array::from_fn(|b| self[b].zip_map(&other[b], &mut f)))
+    }
+
+    /// Sum of the tensors of this batch.
+    pub fn sum(&self) -> Tensor<T, N> {
+        self.iter().fold(Tensor::zero(), |sum, x| &sum + x)
+    }
+
+    /// Mean of the tensors of this batch.
+    pub fn mean(&self) -> Tensor<T, N> {
+        self.sum() / T::from_f64(B as f64)
+    }
+
+    /// Index of the largest value of each tensor of this batch.
+    pub fn argmax(&self) -> [usize; B] {
+        std::array::from_fn(|b| self[b].argmax())
+    }
+
+    /// Product of this batch, as a `B`×`N` matrix, with a vector.
+    pub fn matvec(&self, vector: &Tensor<T, N>) -> Tensor<T, B> {
+        Tensor::from_fn(|b| self[b].dot(vector))
+    }
+
+    /// Product of this batch, as a `B`×`N` matrix, with an `N`×`M` matrix.
+    pub fn matmul<const M: usize>(&self, other: &Batch<N, T, M>) -> Batch<B, T, M> {
+        let mut result = Batch::<B, T, M>::zero();
+        for b in 0..B {
+            let row = self[b].as_array();
+            let output = result[b].as_mut_array();
+            for k in 0..N {
+                let column = other[k].as_array();
+                for j in 0..M {
+                    output[j] = output[j] + row[k] * column[j];
+                }
+            }
+        }
+        result
+    }
+
+    /// Transpose of this batch, as a `B`×`N` matrix.
+    pub fn transpose(&self) -> Batch<N, T, B> {
+        Batch (std::array::from_fn(|i| Tensor::from_fn(|b| self[b][i])))
+    }
+}
+
+impl<'a, const B: usize, T: Numeric, const N: usize> IntoIterator for &'a Batch<B, T, N> {
+    type Item = &'a Tensor<T, N>;
+    type IntoIter = std::slice::Iter<'a, Tensor<T, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implement an elementwise operator for `Tensor`s and `Batch`es, with other tensors
+/// or batches of the same shape and with scalars.
+macro_rules! elementwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T: Numeric, const N: usize> $trait<&Tensor<T, N>> for &Tensor<T, N> {
+            type Output = Tensor<T, N>;
+
+            fn $method(self, other: &Tensor<T, N>) -> Tensor<T, N> {
+                self.zip_map(other, |a, b| a $op b)
+            }
+        }
+
+        impl<T: Numeric, const N: usize> $trait for Tensor<T, N> {
+            type Output = Tensor<T, N>;
+
+            fn $method(self, other: Tensor<T, N>) -> Tensor<T, N> {
+                &self $op &other
+            }
+        }
+
+        impl<T: Numeric, const N: usize> $trait<T> for &Tensor<T, N> {
+            type Output = Tensor<T, N>;
+
+            fn $method(self, other: T) -> Tensor<T, N> {
+                self.map(|a| a $op other)
+            }
+        }
+
+        impl<T: Numeric, const N: usize> $trait<T> for Tensor<T, N> {
+            type Output = Tensor<T, N>;
+
+            fn $method(self, other: T) -> Tensor<T, N> {
+                &self $op other
+            }
+        }
+
+        impl<const B: usize, T: Numeric, const N: usize> $trait<&Batch<B, T, N>> for &Batch<B, T, N> {
+            type Output = Batch<B, T, N>;
+
+            fn $method(self, other: &Batch<B, T, N>) -> Batch<B, T, N> {
+                self.zip_map(other, |a, b| a $op b)
+            }
+        }
+
+        impl<const B: usize, T: Numeric, const N: usize> $trait for Batch<B, T, N> {
+            type Output = Batch<B, T, N>;
+
+            fn $method(self, other: Batch<B, T, N>) -> Batch<B, T, N> {
+                &self $op &other
+            }
+        }
+
+        impl<const B: usize, T: Numeric, const N: usize> $trait<T> for &Batch<B, T, N> {
+            type Output = Batch<B, T, N>;
+
+            fn $method(self, other: T) -> Batch<B, T, N> {
+                self.map(|a| a $op other)
+            }
+        }
+
+        impl<const B: usize, T: Numeric, const N: usize> $trait<T> for Batch<B, T, N> {
+            type Output = Batch<B, T, N>;
+
+            fn $method(self, other: T) -> Batch<B, T, N> {
+                &self $op other
+            }
+        }
+    };
+}
+
+elementwise!(Add, add, +);
+elementwise!(Sub, sub, -);
+elementwise!(Mul, mul, *);
+elementwise!(Div, div, /);
+
+impl<T: Numeric, const N: usize> Neg for Tensor<T, N> {
+    type Output = Tensor<T, N>;
+
+    fn neg(self) -> Tensor<T, N> {
+        self.map(|a| -a)
+    }
+}
+
+impl<T: Numeric, const N: usize> AddAssign<&Tensor<T, N>> for Tensor<T, N> {
+    fn add_assign(&mut self, other: &Tensor<T, N>) {
+        for (a, &b) in self.iter_mut().zip(other) {
+            *a = *a + b;
+        }
+    }
+}
+
+impl<T: Numeric, const N: usize> SubAssign<&Tensor<T, N>> for Tensor<T, N> {
+    fn sub_assign(&mut self, other: &Tensor<T, N>) {
+        for (a, &b) in self.iter_mut().zip(other) {
+            *a = *a - b;
+        }
+    }
+}
+
+impl<const B: usize, T: Numeric, const N: usize> Neg for Batch<B, T, N> {
+    type Output = Batch<B, T, N>;
+
+    fn neg(self) -> Batch<B, T, N> {
+        self.map(|a| -a)
+    }
+}
+
+#[test]
+fn test_tensor_ops() {
+    let a = Tensor::<f64, 3>::new([1.0, 4.0, 2.0]);
+    let b = Tensor::<f64, 3>::new([3.0, 2.0, 1.0]);
+
+    assert_eq!(&a + &b, Tensor::new([4.0, 6.0, 3.0]));
+    assert_eq!(&a - &b, Tensor::new([-2.0, 2.0, 1.0]));
+    assert_eq!(&a * 2.0, Tensor::new([2.0, 8.0, 4.0]));
+    assert_eq!(a.clone() / b.clone(), Tensor::new([1.0 / 3.0, 2.0, 2.0]));
+    assert_eq!(-a.clone(), Tensor::new([-1.0, -4.0, -2.0]));
+
+    assert_eq!(a.sum(), 7.0);
+    assert_eq!(a.mean(), 7.0 / 3.0);
+    assert_eq!(a.max(), 4.0);
+    assert_eq!(a.argmax(), 1);
+    assert_eq!(a.dot(&b), 13.0);
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1.0, 4.0, 2.0]);
+
+    let mut c = a.clone();
+    c += &b;
+    c -= &a;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn test_batch_ops() {
+    let a = Batch::<2, f64, 3>([
+        Tensor::new([1.0, 2.0, 3.0]),
+        Tensor::new([4.0, 5.0, 6.0]),
+    ]);
+    let identity = Batch::<3, f64, 3>([
+        Tensor::new([1.0, 0.0, 0.0]),
+        Tensor::new([0.0, 1.0, 0.0]),
+        Tensor::new([0.0, 0.0, 1.0]),
+    ]);
+
+    assert_eq!(a.sum(), Tensor::new([5.0, 7.0, 9.0]));
+    assert_eq!(a.mean(), Tensor::new([2.5, 3.5, 4.5]));
+    assert_eq!(a.argmax(), [2, 2]);
+    assert_eq!((&a - 1.0)[1], Tensor::new([3.0, 4.0, 5.0]));
+    assert_eq!((&a + &a)[0], Tensor::new([2.0, 4.0, 6.0]));
+
+    assert_eq!(a.matvec(&Tensor::new([1.0, 0.0, -1.0])), Tensor::new([-2.0, -2.0]));
+    assert_eq!(a.matmul(&identity), a);
+
+    // Gram matrix of the rows
+    let gram = a.matmul(&a.transpose());
+    assert_eq!(gram[0], Tensor::new([14.0, 32.0]));
+    assert_eq!(gram[1], Tensor::new([32.0, 77.0]));
+}
@@ -0,0 +1,176 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Shape-typed tensor implementation.
+
+use std::{
+    ops::{
+        Index,
+        IndexMut,
+    },
+    sync::Arc,
+};
+
+use crate::{
+    Numeric,
+    tensor::{
+        DynTensor,
+        Tensor,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+/// Matrix or grayscale image of `H` rows of `W` values of type `T`.
+/// 
+/// A `Tensor2` converts to and from a `Tensor<T, N>` in row-major order.  These
+/// conversions check at compile time that `N = H*W`.
+pub struct Tensor2<T: Numeric, const H: usize, const W: usize> (Arc<[T]>);
+
+impl<T: Numeric, const H: usize, const W: usize> Tensor2<T, H, W> {
+    /// Construct a new tensor from an array of rows.
+    pub fn new(values: [[T; W]; H]) -> Self {
+        Self (values.as_flattened().into())
+    }
+
+    /// Construct a new tensor of zeros.
+    pub fn zero() -> Self {
+        Self::from_fn(|_, _| T::zero())
+    }
+
+    /// Construct a new tensor, computing the value at each row `y` and column `x` with `f`.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        Self ((0..H*W).map(|i| f(i / W, i % W)).collect())
+    }
+
+    /// Construct a new tensor from a flattened tensor in row-major order.
+    pub fn from_tensor<const N: usize>(tensor: &Tensor<T, N>) -> Self {
+        const { assert!(N == H * W, "flattened size must be H*W") };
+        Self (tensor.as_array().as_slice().into())
+    }
+
+    /// Flatten this tensor in row-major order.
+    pub fn flatten<const N: usize>(&self) -> Tensor<T, N> {
+        const { assert!(N == H * W, "flattened size must be H*W") };
+        Tensor::from_fn(|i| self.0[i])
+    }
+
+    /// Get the values of this tensor in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Numeric, const H: usize, const W: usize> Index<(usize, usize)> for Tensor2<T, H, W> {
+    type Output = T;
+
+    fn index(&self, (y, x): (usize, usize)) -> &Self::Output {
+        assert!(y < H && x < W, "index ({}, {}) out of bounds for shape ({}, {})", y, x, H, W);
+        &self.0[y*W+x]
+    }
+}
+
+impl<T: Numeric, const H: usize, const W: usize> IndexMut<(usize, usize)> for Tensor2<T, H, W> {
+    fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut Self::Output {
+        assert!(y < H && x < W, "index ({}, {}) out of bounds for shape ({}, {})", y, x, H, W);
+        &mut Arc::make_mut(&mut self.0)[y*W+x]
+    }
+}
+
+impl<T: Numeric, const H: usize, const W: usize> From<Tensor2<T, H, W>> for DynTensor<T> {
+    fn from(tensor: Tensor2<T, H, W>) -> Self {
+        DynTensor::new(tensor.0.to_vec(), &[H, W]).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Feature map of `C` channels of `H` rows of `W` values of type `T`.
+/// 
+/// A `Tensor3` converts to and from a `Tensor<T, N>` in channel-major, then
+/// row-major, order.  These conversions check at compile time that `N = C*H*W`.
+pub struct Tensor3<T: Numeric, const C: usize, const H: usize, const W: usize> (Arc<[T]>);
+
+impl<T: Numeric, const C: usize, const H: usize, const W: usize> Tensor3<T, C, H, W> {
+    /// Construct a new tensor from an array of channels.
+    pub fn new(values: [[[T; W]; H]; C]) -> Self {
+        Self (values.as_flattened().as_flattened().into())
+    }
+
+    /// Construct a new tensor of zeros.
+    pub fn zero() -> Self {
+        Self::from_fn(|_, _, _| T::zero())
+    }
+
+    /// Construct a new tensor, computing the value at each channel `c`, row `y` and
+    /// column `x` with `f`.
+    pub fn from_fn<F: FnMut(usize, usize, usize) -> T>(mut f: F) -> Self {
+        Self ((0..C*H*W).map(|i| f(i / (H*W), i / W % H, i % W)).collect())
+    }
+
+    /// Construct a new tensor from a flattened tensor.
+    pub fn from_tensor<const N: usize>(tensor: &Tensor<T, N>) -> Self {
+        const { assert!(N == C * H * W, "flattened size must be C*H*W") };
+        Self (tensor.as_array().as_slice().into())
+    }
+
+    /// Flatten this tensor.
+    pub fn flatten<const N: usize>(&self) -> Tensor<T, N> {
+        const { assert!(N == C * H * W, "flattened size must be C*H*W") };
+        Tensor::from_fn(|i| self.0[i])
+    }
+
+    /// Get a copy of channel `c`.
+    pub fn channel(&self, c: usize) -> Tensor2<T, H, W> {
+        Tensor2 (self.0[c*H*W..(c+1)*H*W].into())
+    }
+
+    /// Get the values of this tensor in channel-major, then row-major, order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Numeric, const C: usize, const H: usize, const W: usize> Index<(usize, usize, usize)> for Tensor3<T, C, H, W> {
+    type Output = T;
+
+    fn index(&self, (c, y, x): (usize, usize, usize)) -> &Self::Output {
+        assert!(c < C && y < H && x < W, "index ({}, {}, {}) out of bounds for shape ({}, {}, {})", c, y, x, C, H, W);
+        &self.0[(c*H+y)*W+x]
+    }
+}
+
+impl<T: Numeric, const C: usize, const H: usize, const W: usize> IndexMut<(usize, usize, usize)> for Tensor3<T, C, H, W> {
+    fn index_mut(&mut self, (c, y, x): (usize, usize, usize)) -> &mut Self::Output {
+        assert!(c < C && y < H && x < W, "index ({}, {}, {}) out of bounds for shape ({}, {}, {})", c, y, x, C, H, W);
+        &mut Arc::make_mut(&mut self.0)[(c*H+y)*W+x]
+    }
+}
+
+impl<T: Numeric, const C: usize, const H: usize, const W: usize> From<Tensor3<T, C, H, W>> for DynTensor<T> {
+    fn from(tensor: Tensor3<T, C, H, W>) -> Self {
+        DynTensor::new(tensor.0.to_vec(), &[C, H, W]).unwrap()
+    }
+}
+
+#[test]
+fn test_shaped_tensors() {
+    let image = Tensor2::<f64, 2, 3>::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+    ]);
+    assert_eq!(image[(1, 0)], 4.0);
+
+    // Round trip through a flattened tensor
+    let flat: Tensor<f64, 6> = image.flatten();
+    assert_eq!(flat, Tensor::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(Tensor2::<f64, 2, 3>::from_tensor(&flat), image);
+
+    let mut fmap = Tensor3::<f64, 2, 2, 3>::from_fn(|c, y, x| (c*100 + y*10 + x) as f64);
+    assert_eq!(fmap[(1, 1, 2)], 112.0);
+    assert_eq!(fmap.channel(0), Tensor2::from_fn(|y, x| (y*10 + x) as f64));
+    fmap[(0, 0, 0)] = -1.0;
+    assert_eq!(fmap.flatten::<12>()[0], -1.0);
+
+    let dynamic: DynTensor<f64> = fmap.into();
+    assert_eq!(dynamic.shape(), &[2, 2, 3]);
+    assert_eq!(dynamic[&[1, 0, 1][..]], 101.0);
+}
@@ -0,0 +1,141 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Dropout training and inference testbench.
+
+use alan::{
+    models::Architecture,
+    network::{
+        activation::Identity,
+        Layer,
+        layer::{
+            Dropout,
+            Linear,
+        },
+    },
+    optim::{
+        Hyperparameters,
+        loss::MSELoss,
+        Parameter,
+    },
+    seed,
+    tensor::{
+        Batch,
+        Dataset,
+        Tensor,
+    },
+};
+
+/// Linear model with dropout on its input.
+struct DropoutRegressor {
+    dropout: Dropout<4, f64, 8, 50>,
+    linear: Linear<4, f64, 8, 1>,
+}
+
+impl Architecture<4, f64, 8, 1> for DropoutRegressor {
+    type LossFunction = MSELoss<4, f64, 1>;
+
+    type Activation = Identity<1>;
+
+    fn new() -> Self {
+        Self {
+            dropout: Dropout::new(),
+            linear: Linear::new(),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<4, f64, 8>) -> Batch<4, f64, 1> {
+        self.linear.forward(&self.dropout.forward(batch))
+    }
+
+    fn backward(&mut self, gradients: &Batch<4, f64, 1>) {
+        self.dropout.backward(&self.linear.backward(gradients));
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, f64>> {
+        self.linear.parameters()
+    }
+
+    fn buffers(&mut self) -> Vec<&mut [f64]> {
+        let mut buffers = self.dropout.buffers();
+        buffers.extend(self.linear.buffers());
+        buffers
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.dropout.set_training(training);
+        self.linear.set_training(training);
+    }
+}
+
+/// Dataset of 8 random inputs, labelled by their sum.
+fn dataset() -> Dataset<4, f64, 8, 1> {
+    let data: Vec<Tensor<f64, 8>> = (0..8).map(|i| Tensor::from_fn(|j| ((i * 8 + j) % 5) as f64 / 5.0)).collect();
+    let labels = data.iter().map(|x| Tensor::new([x.sum()])).collect();
+    Dataset::new(data, labels).unwrap()
+}
+
+#[test]
+fn test_dropout_modes() {
+    seed(1);
+    let mut model = DropoutRegressor::new();
+    let batch = dataset().next().unwrap().0;
+
+    // Training mode is stochastic
+    let first = model.forward(&batch);
+    let second = model.forward(&batch);
+    assert_ne!(first, second);
+
+    // Evaluation disables dropout
+    let first = model.eval(&batch);
+    let second = model.eval(&batch);
+    assert_eq!(first, second);
+
+    // Training enables it again
+    model.train(&mut dataset(), Hyperparameters::new(1, 0.01));
+    assert_ne!(model.forward(&batch), model.forward(&batch));
+}
+
+#[test]
+fn test_dropout_inference_mode_sticky() {
+    seed(4);
+    let mut model = DropoutRegressor::new();
+    let batch = dataset().next().unwrap().0;
+
+    // Evaluation leaves the model in inference mode
+    model.eval(&batch);
+    assert_eq!(model.forward(&batch), model.forward(&batch));
+
+    // So does testing, even after training
+    model.train(&mut dataset(), Hyperparameters::new(1, 0.01));
+    model.test(&mut dataset());
+    assert_eq!(model.forward(&batch), model.forward(&batch));
+
+    // Until training mode is set again
+    model.set_training(true);
+    assert_ne!(model.forward(&batch), model.forward(&batch));
+}
+
+#[test]
+fn test_dropout_full_batch_mode() {
+    seed(3);
+    let mut model = DropoutRegressor::new();
+    let batch = dataset().next().unwrap().0;
+
+    // Full-batch objectives run in training mode, even after evaluation
+    model.eval(&batch);
+    let values = model.flatten();
+    model.loss_and_gradient(&mut dataset(), &values);
+    assert_ne!(model.forward(&batch), model.forward(&batch));
+}
+
+#[test]
+fn test_dropout_reproducible() {
+    let mut losses = Vec::new();
+    for _ in 0..2 {
+        seed(2);
+        let mut model = DropoutRegressor::new();
+        losses.push(model.train(&mut dataset(), Hyperparameters::new(10, 0.01)));
+    }
+    assert_eq!(losses[0], losses[1]);
+}
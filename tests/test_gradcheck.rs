@@ -0,0 +1,241 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Gradient checking testbench.
+
+use alan::{
+    autodiff::{
+        gradcheck,
+        gradcheck_activation,
+        gradcheck_loss,
+    },
+    Numeric,
+    network::{
+        activation::{
+            Identity,
+            ReLU,
+            Softmax,
+        },
+        Activation,
+        layer::{
+            AvgPool,
+            AvgPool1d,
+            AvgPool3d,
+            BatchNorm,
+            Conv1d,
+            Conv2d,
+            Conv3d,
+            Convolution,
+            ConvTranspose,
+            DepthwiseSeparable,
+            Embedding,
+            GlobalAvgPool,
+            GlobalMaxPool,
+            GroupedConv2d,
+            Gru,
+            Interpolation,
+            LayerNorm,
+            Linear,
+            Lstm,
+            MaxPool,
+            MaxPool1d,
+            MaxPool3d,
+            MinPool,
+            MultiHeadAttention,
+            Padding,
+            Rnn,
+            Upsample,
+        },
+        Layer,
+    },
+    optim::{
+        Loss,
+        loss::{
+            CrossEntropyLoss,
+            MSELoss,
+        },
+    },
+    tensor::{
+        Batch,
+        Tensor,
+    },
+};
+
+/// Maximum relative error allowed.
+const TOLERANCE: f64 = 1e-6;
+
+/// Absolute error allowed, for gradients too small for their relative error to be
+/// meaningful; central differences carry rounding error of about
+/// `1e-16 * |objective| / EPSILON`, i.e. `1e-9` for the objectives below.
+const ATOL: f64 = 1e-8;
+
+/// Finite difference step.
+const EPSILON: f64 = 1e-6;
+
+/// Random batch with values in `[-1, 1)`.
+fn random_batch<const B: usize, const N: usize>() -> Batch<B, f64, N> {
+    let mut batch = Batch::zero();
+    for b in 0..B {
+        for i in 0..N {
+            batch[b][i] = 2.0 * f64::random() - 1.0;
+        }
+    }
+    batch
+}
+
+#[test]
+fn test_gradcheck_layers() {
+    let mut linear = Linear::<3, f64, 4, 2>::new();
+    assert!(gradcheck(&mut linear, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut conv = Convolution::<2, f64, 5, 4, 20, 3, 2, 6, 3>::new();
+    assert!(gradcheck(&mut conv, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut conv2d = Conv2d::<2, f64, 2, 5, 4, 40, 3, 3, 2, 18, 2>::new();
+    conv2d.stride = 2;
+    conv2d.padding = 1;
+    conv2d.dilation = 2;
+    for padding_mode in [Padding::Zero, Padding::Reflect, Padding::Replicate] {
+        conv2d.padding_mode = padding_mode;
+        assert!(gradcheck(&mut conv2d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+    }
+
+    let mut conv1d = Conv1d::<2, f64, 2, 7, 14, 3, 4, 12, 3>::new();
+    conv1d.stride = 2;
+    conv1d.padding = 1;
+    conv1d.padding_mode = Padding::Reflect;
+    assert!(gradcheck(&mut conv1d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut conv3d = Conv3d::<2, f64, 2, 3, 3, 3, 54, 2, 2, 2, 2, 16, 2>::new();
+    conv3d.stride = 2;
+    conv3d.padding = 1;
+    conv3d.padding_mode = Padding::Replicate;
+    assert!(gradcheck(&mut conv3d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut grouped = GroupedConv2d::<2, f64, 4, 4, 3, 48, 6, 3, 2, 36, 2, 2, 2>::new();
+    grouped.padding_mode = Padding::Reflect;
+    assert!(gradcheck(&mut grouped, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut separable = DepthwiseSeparable::<2, f64, 3, 4, 4, 48, 2, 2, 2, 12, 8, 3>::new();
+    separable.depthwise.stride = 2;
+    separable.depthwise.padding = 1;
+    assert!(gradcheck(&mut separable, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut convt = ConvTranspose::<2, f64, 3, 2, 6, 5, 3, 15, 3>::new();
+    convt.stride = 2;
+    convt.padding = 1;
+    assert!(gradcheck(&mut convt, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut upsample = Upsample::<2, f64, 3, 2, 6, 7, 5, 35>::new();
+    for mode in [Interpolation::Nearest, Interpolation::Bilinear] {
+        upsample.mode = mode;
+        assert!(gradcheck(&mut upsample, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+    }
+
+    let mut avgpool = AvgPool::<2, f64, 6, 4, 24, 3, 2, 6, 2>::new();
+    assert!(gradcheck(&mut avgpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut avgpool = AvgPool::<2, f64, 5, 5, 25, 3, 3, 9, 3>::learnable();
+    avgpool.stride = 2;
+    avgpool.padding = 1;
+    assert!(gradcheck(&mut avgpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut maxpool = MaxPool::<2, f64, 5, 5, 25, 2, 2, 4, 3>::new();
+    maxpool.stride = 2;
+    assert!(gradcheck(&mut maxpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut minpool = MinPool::<2, f64, 4, 4, 16, 3, 3, 9, 2>::new();
+    minpool.padding = 1;
+    assert!(gradcheck(&mut minpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut avgpool1d = AvgPool1d::<2, f64, 7, 4, 3>::learnable();
+    avgpool1d.stride = 2;
+    avgpool1d.padding = 1;
+    assert!(gradcheck(&mut avgpool1d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut avgpool3d = AvgPool3d::<2, f64, 3, 3, 3, 27, 2, 2, 2, 8, 2>::learnable();
+    avgpool3d.stride = 1;
+    assert!(gradcheck(&mut avgpool3d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut maxpool1d = MaxPool1d::<2, f64, 8, 4, 2>::new();
+    assert!(gradcheck(&mut maxpool1d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut maxpool3d = MaxPool3d::<2, f64, 4, 4, 2, 32, 2, 2, 1, 4, 2>::new();
+    assert!(gradcheck(&mut maxpool3d, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut global_avgpool = GlobalAvgPool::<2, f64, 2, 3, 2, 12>::new();
+    assert!(gradcheck(&mut global_avgpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut global_maxpool = GlobalMaxPool::<2, f64, 2, 3, 2, 12>::new();
+    assert!(gradcheck(&mut global_maxpool, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut batchnorm = BatchNorm::<3, f64, 2, 6>::new();
+    assert!(gradcheck(&mut batchnorm, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+    batchnorm.set_training(false);
+    assert!(gradcheck(&mut batchnorm, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut layernorm = LayerNorm::<2, f64, 5>::new();
+    assert!(gradcheck(&mut layernorm, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+}
+
+#[test]
+fn test_gradcheck_recurrent() {
+    let mut rnn = Rnn::<2, f64, 2, 3, 4, 8, 12>::new();
+    assert!(gradcheck(&mut rnn, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut lstm = Lstm::<2, f64, 2, 3, 4, 8, 12>::new();
+    assert!(gradcheck(&mut lstm, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    let mut gru = Gru::<2, f64, 2, 3, 4, 8, 12>::new();
+    assert!(gradcheck(&mut gru, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+}
+
+#[test]
+fn test_gradcheck_activations() {
+    let mut identity: Identity<3> = Activation::<2, f64, 3>::new();
+    assert!(gradcheck_activation::<2, f64, 3, _>(&mut identity, &random_batch(), EPSILON).max() < TOLERANCE);
+
+    let mut relu = ReLU::<2, f64, 3>::new();
+    let input = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([-1.0, 0.5, 2.0]),
+        Tensor::<f64, 3>::new([0.25, -0.5, -2.0]),
+    ]);
+    assert!(gradcheck_activation(&mut relu, &input, EPSILON).max() < TOLERANCE);
+
+    let mut softmax = Softmax::<2, f64, 3>::new();
+    assert!(gradcheck_activation(&mut softmax, &random_batch(), EPSILON).max() < TOLERANCE);
+}
+
+#[test]
+fn test_gradcheck_losses() {
+    let labels = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([0.0, 1.0, 0.0]),
+        Tensor::<f64, 3>::new([0.0, 0.0, 1.0]),
+    ]);
+
+    let mut mse = MSELoss::<2, f64, 3>::new();
+    assert!(gradcheck_loss(&mut mse, &random_batch(), &labels, EPSILON).max() < TOLERANCE);
+
+    let mut xent = CrossEntropyLoss::<2, f64, 3>::new();
+    assert!(gradcheck_loss(&mut xent, &random_batch(), &labels, EPSILON).max() < TOLERANCE);
+}
+
+#[test]
+fn test_gradcheck_attention() {
+    let mut attention = MultiHeadAttention::<2, f64, 3, 4, 2, 12, 6>::new();
+    assert!(gradcheck(&mut attention, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+
+    attention.causal = true;
+    assert!(gradcheck(&mut attention, &random_batch(), EPSILON).allclose(TOLERANCE, ATOL));
+}
+
+#[test]
+fn test_gradcheck_embedding() {
+    // Perturbed indices round to the same row, so input gradients are zero
+    let indices = Batch::<2, f64, 3> ([
+        Tensor::<f64, 3>::new([0.0, 4.0, 2.0]),
+        Tensor::<f64, 3>::new([2.0, 2.0, 1.0]),
+    ]);
+    let mut embedding = Embedding::<2, f64, 6, 4, 3, 12>::new();
+    assert!(gradcheck(&mut embedding, &indices, EPSILON).allclose(TOLERANCE, ATOL));
+}
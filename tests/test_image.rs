@@ -28,12 +28,12 @@ fn load_image(path: &str) -> Tensor<f32, 65536> {
     // Convert image to tensor
     let array: [f32; 65536] = (
         *image.as_raw()
-        .into_iter()
+        .iter()
         .map(|x| *x as f32 / 255.0)
         .collect::<Vec<f32>>()
     ).try_into().unwrap();
 
-    Tensor (array)
+    Tensor::new(array)
 }
 
 #[test]
@@ -42,12 +42,12 @@ fn train_image_classifier() {
     let mit          = load_image("images/mit.jpg");
     let mut dataset: Dataset<2, f32, 65536, 2> = Dataset::new(
         vec![
-            fire_hydrant,
-            mit,
+            fire_hydrant.clone(),
+            mit.clone(),
         ],
         vec![
-            Tensor::<f32, 2> ([1.0, 0.0]),
-            Tensor::<f32, 2> ([0.0, 1.0]),
+            Tensor::<f32, 2>::new([1.0, 0.0]),
+            Tensor::<f32, 2>::new([0.0, 1.0]),
         ]
     ).unwrap();
 
@@ -55,7 +55,7 @@ fn train_image_classifier() {
     let mut classifier: ImageClassifier<2, f32, 2> = ImageClassifier::new();
 
     // Train classifier
-    let h = Hyperparameters {epochs: 50, lr: 1e-4};
+    let h = Hyperparameters::new(50, 1e-4);
     classifier.train(&mut dataset, h);
 
     // Compute class probabilities
@@ -18,17 +18,17 @@ use alan::{
 #[test]
 fn test_linear_regressor() {
     let data = vec![
-        Tensor::<f64, 1> ([0.0]),
-        Tensor::<f64, 1> ([1.0]),
-        Tensor::<f64, 1> ([2.0]),
-        Tensor::<f64, 1> ([3.0]),
+        Tensor::<f64, 1>::new([0.0]),
+        Tensor::<f64, 1>::new([1.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([3.0]),
     ];
 
     let labels = vec![
-        Tensor::<f64, 1> ([0.0]),
-        Tensor::<f64, 1> ([2.0]),
-        Tensor::<f64, 1> ([4.0]),
-        Tensor::<f64, 1> ([6.0]),
+        Tensor::<f64, 1>::new([0.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([4.0]),
+        Tensor::<f64, 1>::new([6.0]),
     ];
 
     // Initialize dataset
@@ -40,23 +40,23 @@ fn test_linear_regressor() {
     let mut model = LinearRegressor::<4, f64>::new();
 
     // Set up hyperparameters
-    let h = Hyperparameters {epochs: 100, lr: 0.1};
+    let h = Hyperparameters::new(100, 0.1);
 
     // Train model
     model.train(&mut dataset, h);
 
     // New data!
     let data = [
-        Tensor::<f64, 1> ([4.0]),
-        Tensor::<f64, 1> ([5.0]),
-        Tensor::<f64, 1> ([6.0]),
-        Tensor::<f64, 1> ([7.0]),
+        Tensor::<f64, 1>::new([4.0]),
+        Tensor::<f64, 1>::new([5.0]),
+        Tensor::<f64, 1>::new([6.0]),
+        Tensor::<f64, 1>::new([7.0]),
     ];
     let labels = [
-        Tensor::<f64, 1> ([8.0]),
-        Tensor::<f64, 1> ([10.0]),
-        Tensor::<f64, 1> ([12.0]),
-        Tensor::<f64, 1> ([14.0]),
+        Tensor::<f64, 1>::new([8.0]),
+        Tensor::<f64, 1>::new([10.0]),
+        Tensor::<f64, 1>::new([12.0]),
+        Tensor::<f64, 1>::new([14.0]),
     ];
     let dataset = Dataset::<4, f64, 1, 1>::new(data.into(), labels.into());
     assert!(dataset.is_some());
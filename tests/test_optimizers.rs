@@ -0,0 +1,300 @@
+//! ALAN
+//! Copyright (c) 2025 J. Hobbs
+//! 
+//! Optimizer testbench.
+
+use alan::{
+    models::{
+        Architecture,
+        regressors::LinearRegressor,
+    },
+    network::{
+        activation::Identity,
+        Layer,
+        layer::{
+            Embedding,
+            Linear,
+        },
+    },
+    optim::{
+        Adagrad,
+        Adam,
+        AdamW,
+        Algorithm,
+        ConjugateGradient,
+        Hyperparameters,
+        LBFGS,
+        loss::MSELoss,
+        Minimizer,
+        Optimizer,
+        Parameter,
+        RMSProp,
+        SGD,
+    },
+    tensor::{
+        Batch,
+        Dataset,
+        Tensor,
+    },
+    x16,
+};
+
+/// Linear model of a learned embedding of one token from a vocabulary of 8.
+struct EmbeddingRegressor {
+    embedding: Embedding<4, f64, 8, 2, 1, 2>,
+    linear: Linear<4, f64, 2, 1>,
+}
+
+impl Architecture<4, f64, 1, 1> for EmbeddingRegressor {
+    type LossFunction = MSELoss<4, f64, 1>;
+
+    type Activation = Identity<1>;
+
+    fn new() -> Self {
+        Self {
+            embedding: Embedding::new(),
+            linear: Linear::new(),
+        }
+    }
+
+    fn forward(&mut self, batch: &Batch<4, f64, 1>) -> Batch<4, f64, 1> {
+        self.linear.forward(&self.embedding.forward(batch))
+    }
+
+    fn backward(&mut self, gradients: &Batch<4, f64, 1>) {
+        self.embedding.backward(&self.linear.backward(gradients));
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter<'_, f64>> {
+        let mut parameters = self.embedding.parameters();
+        parameters.extend(self.linear.parameters());
+        parameters
+    }
+
+    fn buffers(&mut self) -> Vec<&mut [f64]> {
+        let mut buffers = self.embedding.buffers();
+        buffers.extend(self.linear.buffers());
+        buffers
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.embedding.set_training(training);
+        self.linear.set_training(training);
+    }
+}
+
+/// Training and testing datasets for `y = 2x`.
+fn datasets() -> (Dataset<4, f64, 1, 1>, Dataset<4, f64, 1, 1>) {
+    let data = vec![
+        Tensor::<f64, 1>::new([0.0]),
+        Tensor::<f64, 1>::new([1.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([3.0]),
+    ];
+    let labels = vec![
+        Tensor::<f64, 1>::new([0.0]),
+        Tensor::<f64, 1>::new([2.0]),
+        Tensor::<f64, 1>::new([4.0]),
+        Tensor::<f64, 1>::new([6.0]),
+    ];
+    let train = Dataset::<4, f64, 1, 1>::new(data, labels).unwrap();
+
+    let data = vec![
+        Tensor::<f64, 1>::new([4.0]),
+        Tensor::<f64, 1>::new([5.0]),
+        Tensor::<f64, 1>::new([6.0]),
+        Tensor::<f64, 1>::new([7.0]),
+    ];
+    let labels = vec![
+        Tensor::<f64, 1>::new([8.0]),
+        Tensor::<f64, 1>::new([10.0]),
+        Tensor::<f64, 1>::new([12.0]),
+        Tensor::<f64, 1>::new([14.0]),
+    ];
+    let test = Dataset::<4, f64, 1, 1>::new(data, labels).unwrap();
+
+    (train, test)
+}
+
+/// Train a linear regressor on `y = 2x` with the given optimizer and
+/// return the loss on unseen data.
+fn fit_linear_regressor<O: Optimizer<f64>>(optimizer: &mut O, h: Hyperparameters<f64>) -> f64 {
+    let (mut train, mut test) = datasets();
+    let mut model = LinearRegressor::<4, f64>::new();
+    model.train_with(&mut train, h, optimizer);
+    model.test(&mut test)
+}
+
+/// Train a linear regressor on `y = 2x` with the optimizer selected in
+/// the given hyperparameters and return the loss on unseen data.
+fn fit_linear_regressor_with(h: Hyperparameters<f64>) -> f64 {
+    let (mut train, mut test) = datasets();
+    let mut model = LinearRegressor::<4, f64>::new();
+    model.train(&mut train, h);
+    model.test(&mut test)
+}
+
+/// Train a linear regressor on `y = 2x` with the given full-batch minimizer
+/// and return the training loss and the loss on unseen data.
+fn minimize_linear_regressor<S: Minimizer<f64>>(minimizer: &mut S) -> (f64, f64) {
+    let (mut train, mut test) = datasets();
+    let mut model = LinearRegressor::<4, f64>::new();
+    let loss = model.minimize(&mut train, minimizer);
+    (loss, model.test(&mut test))
+}
+
+/// Take one step from `w = 0` with gradient `g` and learning rate `1` over `x16`
+/// with the given optimizer, and return the new value of `w`.
+/// 
+/// NOTE `x16` truncates products and quotients, so `g` is chosen so that every
+/// intermediate value of a step is exact.
+fn step_x16<O: Optimizer<x16>>(optimizer: &mut O, g: f32) -> x16 {
+    let mut w = [x16::from(0.0f32)];
+    let mut gradient = [x16::from(g)];
+    optimizer.step(&mut [Parameter::new(&mut w, &mut gradient)], x16::from(1.0f32));
+    w[0]
+}
+
+#[test]
+fn test_adam() {
+    let h = Hyperparameters::new(1000, 0.05);
+    let loss = fit_linear_regressor(&mut Adam::default(), h);
+    assert!(loss < 1e-3);
+}
+
+/// Dataset of the first `tokens` tokens of the vocabulary of `EmbeddingRegressor`,
+/// each repeated `n / tokens` times.
+fn tokens(tokens: usize, n: usize) -> Dataset<4, f64, 1, 1> {
+    let data: Vec<Tensor<f64, 1>> = (0..n).map(|i| Tensor::new([(i % tokens) as f64])).collect();
+    let labels = data.iter().map(|x| Tensor::new([x[0] - 1.5])).collect();
+    Dataset::new(data, labels).unwrap()
+}
+
+#[test]
+fn test_adam_sparse_embedding() {
+    let mut model = EmbeddingRegressor::new();
+    let mut adam = Adam::default();
+    let h = Hyperparameters::new(20, 0.05);
+    let initial = model.embedding.table.clone();
+
+    // Tokens 6 and 7 are never looked up
+    let losses = model.train_with(&mut tokens(6, 12), h, &mut adam);
+    assert!(losses[losses.len() - 1] < losses[0]);
+    assert_eq!(model.embedding.table[6..], initial[6..]);
+    assert_ne!(model.embedding.table[..6], initial[..6]);
+
+    // Tokens 4 and 5 are no longer looked up, so their moments are not applied again
+    let trained = model.embedding.table.clone();
+    model.train_with(&mut tokens(4, 8), h, &mut adam);
+    assert_eq!(model.embedding.table[4..], trained[4..]);
+    assert_ne!(model.embedding.table[..4], trained[..4]);
+}
+
+#[test]
+fn test_adamw() {
+    // Weight decay pulls weights slightly toward zero, so allow a looser fit
+    let h = Hyperparameters::new(1000, 0.05);
+    let loss = fit_linear_regressor(&mut AdamW::new(0.9, 0.999, 1e-8, 1e-4), h);
+    assert!(loss < 1e-2);
+}
+
+#[test]
+fn test_sgd_momentum() {
+    let h = Hyperparameters {
+        epochs: 200,
+        lr: 0.02,
+        optimizer: Algorithm::SGD {momentum: 0.9, nesterov: false},
+        ..Default::default()
+    };
+    assert!(fit_linear_regressor_with(h) < 1e-3);
+}
+
+#[test]
+fn test_sgd_nesterov() {
+    let h = Hyperparameters {
+        epochs: 200,
+        lr: 0.02,
+        optimizer: Algorithm::SGD {momentum: 0.9, nesterov: true},
+        ..Default::default()
+    };
+    assert!(fit_linear_regressor_with(h) < 1e-3);
+}
+
+#[test]
+fn test_rmsprop() {
+    let h = Hyperparameters {
+        epochs: 2000,
+        lr: 0.01,
+        optimizer: Algorithm::RMSProp {rho: 0.99, epsilon: 1e-8},
+        ..Default::default()
+    };
+    assert!(fit_linear_regressor_with(h) < 1e-2);
+}
+
+#[test]
+fn test_adagrad() {
+    let h = Hyperparameters {
+        epochs: 2000,
+        lr: 0.5,
+        optimizer: Algorithm::Adagrad {epsilon: 1e-8},
+        ..Default::default()
+    };
+    assert!(fit_linear_regressor_with(h) < 1e-3);
+}
+
+#[test]
+fn test_sgd_momentum_x16() {
+    let mut sgd = SGD::new(x16::from(0.5f32), false);
+    assert_eq!(step_x16(&mut sgd, 2.0), x16::from(-2.0f32));
+}
+
+#[test]
+fn test_sgd_nesterov_x16() {
+    // The update looks ahead by `momentum * velocity`
+    let mut sgd = SGD::new(x16::from(0.5f32), true);
+    assert_eq!(step_x16(&mut sgd, 2.0), x16::from(-3.0f32));
+}
+
+#[test]
+fn test_rmsprop_x16() {
+    // The mean square gradient is (1 - rho) * 16 = 4
+    let mut rmsprop = RMSProp::new(x16::from(0.75f32), x16::from(0.0f32));
+    assert_eq!(step_x16(&mut rmsprop, 4.0), x16::from(-2.0f32));
+}
+
+#[test]
+fn test_adagrad_x16() {
+    // The first step moves each value by exactly `lr`
+    let mut adagrad = Adagrad::new(x16::from(0.0f32));
+    assert_eq!(step_x16(&mut adagrad, 4.0), x16::from(-1.0f32));
+}
+
+#[test]
+fn test_lbfgs() {
+    // A quadratic in two parameters needs only a handful of iterations
+    let (train, test) = minimize_linear_regressor(&mut LBFGS::new(5, 10, 1e-10));
+    assert!(train < 1e-8);
+    assert!(test < 1e-6);
+}
+
+#[test]
+fn test_lbfgs_no_history() {
+    // Without history, L-BFGS falls back to steepest descent
+    let (train, _) = minimize_linear_regressor(&mut LBFGS::new(0, 100, 1e-10));
+    assert!(train < 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "dataset must not be empty")]
+fn test_minimize_empty_dataset() {
+    let mut empty = Dataset::<4, f64, 1, 1>::new(Vec::new(), Vec::new()).unwrap();
+    let mut model = LinearRegressor::<4, f64>::new();
+    model.minimize(&mut empty, &mut LBFGS::default());
+}
+
+#[test]
+fn test_conjugate_gradient() {
+    let (train, test) = minimize_linear_regressor(&mut ConjugateGradient::new(10, 1e-10));
+    assert!(train < 1e-8);
+    assert!(test < 1e-6);
+}